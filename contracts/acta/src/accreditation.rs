@@ -0,0 +1,218 @@
+use crate::error::ContractError;
+use crate::storage;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String, Vec};
+
+/// Maximum length of a chain of trust (issuer up to root authority, inclusive).
+pub const MAX_CHAIN_DEPTH: u32 = 8;
+
+/// Accreditation of an address by an accreditation body (or root authority by the admin).
+///
+/// Revocation cascades lazily: a link is valid only if its accreditor's record is still
+/// active and has not been re-issued since (`accreditor_generation`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Accreditation {
+    /// Accredited address.
+    pub subject: Address,
+
+    /// Accreditation body (contract admin for root authorities).
+    pub accreditor: Address,
+
+    /// Credential types covered; empty means all types.
+    pub credential_types: Vec<String>,
+
+    /// Whether the subject may accredit others (accreditation body).
+    pub can_accredit: bool,
+
+    /// Root authorities anchor the chain of trust.
+    pub root: bool,
+
+    /// Incremented every time the subject is (re-)accredited.
+    pub generation: u32,
+
+    /// Accreditor's `generation` when this record was issued.
+    pub accreditor_generation: u32,
+
+    /// Revoked records break every chain going through them.
+    pub revoked: bool,
+
+    /// Ledger timestamp of the accreditation.
+    pub accredited_at: u64,
+}
+
+/// Accreditation check result.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccreditationStatus {
+    /// Whether the address is accredited (for the requested type, if any).
+    pub accredited: bool,
+
+    /// Chain of trust from the address up to its root authority (empty if not accredited).
+    pub chain: Vec<Address>,
+}
+
+pub fn add_root_authority(
+    e: &Env,
+    admin: &Address,
+    authority: &Address,
+    credential_types: &Vec<String>,
+) {
+    write(e, authority, admin, credential_types, true, true, 0);
+}
+
+/// Accredits `subject` under `accreditor`, within the accreditor's own scope.
+///
+/// An existing record can only be replaced by the accreditor that issued it (or the contract
+/// admin); root authorities are never replaced here.
+pub fn accredit(
+    e: &Env,
+    accreditor: &Address,
+    subject: &Address,
+    credential_types: &Vec<String>,
+    can_accredit: bool,
+) {
+    let parent = match storage::read_accreditation(e, accreditor) {
+        Some(parent) => parent,
+        None => panic_with_error!(e, ContractError::NotAccreditor),
+    };
+
+    let chain = match valid_chain(e, accreditor) {
+        Some(chain) => chain,
+        None => panic_with_error!(e, ContractError::NotAccreditor),
+    };
+    // The subject must not already sit above the accreditor (would create a cycle).
+    if !parent.can_accredit || chain.contains(subject.clone()) {
+        panic_with_error!(e, ContractError::NotAccreditor)
+    }
+    if chain.len() >= MAX_CHAIN_DEPTH {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+
+    if !parent.credential_types.is_empty()
+        && (credential_types.is_empty()
+            || credential_types
+                .iter()
+                .any(|t| !parent.credential_types.contains(t)))
+    {
+        panic_with_error!(e, ContractError::AccreditationScopeExceeded)
+    }
+
+    // Another branch's records (and root authorities) are not the accreditor's to replace.
+    if let Some(existing) = storage::read_accreditation(e, subject) {
+        if existing.root
+            || (existing.accreditor != *accreditor
+                && *accreditor != storage::read_contract_admin(e))
+        {
+            panic_with_error!(e, ContractError::Unauthorized)
+        }
+    }
+
+    write(
+        e,
+        subject,
+        accreditor,
+        credential_types,
+        can_accredit,
+        false,
+        parent.generation,
+    );
+}
+
+/// Revokes an accreditation; every chain going through `subject` becomes invalid.
+///
+/// Only the subject's accreditor or the contract admin may revoke.
+pub fn revoke(e: &Env, revoker: &Address, subject: &Address) {
+    let mut record = match storage::read_accreditation(e, subject) {
+        Some(record) if !record.revoked => record,
+        _ => panic_with_error!(e, ContractError::AccreditationNotFound),
+    };
+    if *revoker != record.accreditor && *revoker != storage::read_contract_admin(e) {
+        panic_with_error!(e, ContractError::Unauthorized)
+    }
+    record.revoked = true;
+    storage::write_accreditation(e, subject, &record);
+}
+
+/// Checks whether `subject` is accredited, optionally for a given credential type.
+pub fn status(e: &Env, subject: &Address, credential_type: &Option<String>) -> AccreditationStatus {
+    let not_accredited = AccreditationStatus {
+        accredited: false,
+        chain: Vec::new(e),
+    };
+
+    let record = match storage::read_accreditation(e, subject) {
+        Some(record) => record,
+        None => return not_accredited,
+    };
+    if let Some(t) = credential_type {
+        if !record.credential_types.is_empty() && !record.credential_types.contains(t) {
+            return not_accredited;
+        }
+    }
+
+    match valid_chain(e, subject) {
+        Some(chain) => AccreditationStatus {
+            accredited: true,
+            chain,
+        },
+        None => not_accredited,
+    }
+}
+
+/// Whether `subject` is an active accreditation body.
+pub fn is_accreditor(e: &Env, subject: &Address) -> bool {
+    match storage::read_accreditation(e, subject) {
+        Some(record) => record.can_accredit && valid_chain(e, subject).is_some(),
+        None => false,
+    }
+}
+
+/// Walks from `subject` up to a root authority; `None` if any link is broken.
+fn valid_chain(e: &Env, subject: &Address) -> Option<Vec<Address>> {
+    let mut chain = Vec::new(e);
+    let mut current = storage::read_accreditation(e, subject)?;
+
+    loop {
+        if current.revoked || chain.len() >= MAX_CHAIN_DEPTH {
+            return None;
+        }
+        chain.push_back(current.subject.clone());
+        if current.root {
+            return Some(chain);
+        }
+
+        let parent = storage::read_accreditation(e, &current.accreditor)?;
+        if !parent.can_accredit || parent.generation != current.accreditor_generation {
+            return None;
+        }
+        current = parent;
+    }
+}
+
+fn write(
+    e: &Env,
+    subject: &Address,
+    accreditor: &Address,
+    credential_types: &Vec<String>,
+    can_accredit: bool,
+    root: bool,
+    accreditor_generation: u32,
+) {
+    let generation = match storage::read_accreditation(e, subject) {
+        Some(previous) => previous.generation + 1,
+        None => 0,
+    };
+
+    let record = Accreditation {
+        subject: subject.clone(),
+        accreditor: accreditor.clone(),
+        credential_types: credential_types.clone(),
+        can_accredit,
+        root,
+        generation,
+        accreditor_generation,
+        revoked: false,
+        accredited_at: e.ledger().timestamp(),
+    };
+    storage::write_accreditation(e, subject, &record);
+}
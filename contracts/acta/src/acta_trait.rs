@@ -1,4 +1,19 @@
-use soroban_sdk::{Address, BytesN, Env, Map, String, Vec};
+use crate::accreditation::{Accreditation, AccreditationStatus};
+use crate::fee::{
+    FeeConfig, FeeOperation, FeePayment, FeeQuote, FeeShare, FeeTier, IssuerFee, RefundPolicy,
+    Sponsorship,
+};
+use crate::did_registry::{DidDocument, Service, VerificationMethod};
+use crate::issuer::{IssuerAllowance, IssuerUpdate};
+use crate::issuer_did::IssuerDidBinding;
+use crate::issuer_request::IssuerAccessRequest;
+use crate::recovery::{GuardianConfig, Recovery};
+use crate::schema::{Schema, SchemaRef};
+use crate::sharing::VerificationReceipt;
+use crate::vault::{CloseProgress, RotationProgress, VaultInfo};
+use crate::vault_admins::{AdminAction, AdminSet, Proposal};
+use crate::verifiable_credential::PayloadUpload;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, String, Vec};
 
 /// ACTA unified contract interface.
 ///
@@ -34,15 +49,160 @@ pub trait ActaTrait {
 
     /// Sets global fee configuration (admin-only).
     ///
-    /// Fee charging happens inside `issue` when enabled.
+    /// Fee charging happens inside `issue` when enabled; `fee_amount` is the issuance price unless
+    /// a type or `Issue` operation price applies. `fee_amount` must be non-negative.
     fn set_fee_config(e: Env, token_contract: Address, fee_dest: Address, fee_amount: i128);
 
+    /// Splits every fee across recipients by basis points (admin-only).
+    ///
+    /// Shares must sum to 10000. Each part is rounded down and the remainder goes to the first
+    /// recipient. An empty list sends fees to `fee_dest` again.
+    fn set_fee_shares(e: Env, shares: Vec<FeeShare>);
+
+    /// Returns the fee split (empty when every fee goes to `fee_dest`).
+    fn get_fee_shares(e: Env) -> Vec<FeeShare>;
+
+    /// Sets the input validation limits (admin-only).
+    ///
+    /// Applies to `create_vault`, `issue` and `push`.
+    fn set_validation_limits(e: Env, limits: crate::validation::ValidationLimits);
+
+    /// Returns the current input validation limits.
+    fn get_validation_limits(e: Env) -> crate::validation::ValidationLimits;
+
     /// Upgrades the contract WASM (admin-only).
     fn upgrade(e: Env, new_wasm_hash: BytesN<32>);
 
     /// Returns contract version.
     fn version(e: Env) -> String;
 
+    /// Returns the contract admin.
+    fn get_contract_admin(e: Env) -> Address;
+
+    /// Returns the default issuer DID set at initialization, if any.
+    fn get_default_issuer_did(e: Env) -> Option<String>;
+
+    /// Returns the fee configuration (`None` until `set_fee_config` is called).
+    fn get_fee_config(e: Env) -> Option<FeeConfig>;
+
+    /// Sets or clears the fee manager, who may manage the fee schedule (admin-only).
+    fn set_fee_manager(e: Env, manager: Option<Address>);
+
+    /// Returns the fee manager, if any.
+    fn get_fee_manager(e: Env) -> Option<Address>;
+
+    /// Sets or clears an issuer's fee override (admin or fee manager).
+    ///
+    /// An exempt issuer pays nothing; a fixed `amount` replaces type pricing and volume tiers;
+    /// `token` replaces the global fee token.
+    fn set_issuer_fee(e: Env, caller: Address, issuer: Address, fee: Option<IssuerFee>);
+
+    /// Returns an issuer's fee override, if any.
+    fn get_issuer_fee(e: Env, issuer: Address) -> Option<IssuerFee>;
+
+    /// Sets or clears the price of a credential type, in the global fee token
+    /// (admin or fee manager).
+    fn set_credential_type_fee(
+        e: Env,
+        caller: Address,
+        credential_type: String,
+        amount: Option<i128>,
+    );
+
+    /// Replaces the volume discount tiers (admin or fee manager).
+    ///
+    /// A tier applies once the issuer has issued `min_issuances` credentials (all vaults).
+    fn set_fee_tiers(e: Env, caller: Address, tiers: Vec<FeeTier>);
+
+    /// Returns the volume discount tiers.
+    fn get_fee_tiers(e: Env) -> Vec<FeeTier>;
+
+    /// Deposits prepaid issuance credit into the contract (issuer-signed).
+    ///
+    /// While an issuer has a credit account in the fee token, `issue` draws the fee from it
+    /// instead of transferring from the issuer, and fails with `InsufficientCredit` once the
+    /// balance does not cover it.
+    fn deposit_credit(e: Env, issuer: Address, token: Address, amount: i128);
+
+    /// Withdraws unused credit (issuer-signed). The account stays open, even at zero.
+    fn withdraw_credit(e: Env, issuer: Address, token: Address, amount: i128);
+
+    /// Refunds the remaining credit and closes the account (issuer-signed), so fees are
+    /// transferred from the issuer again. Returns the amount refunded.
+    fn close_credit(e: Env, issuer: Address, token: Address) -> i128;
+
+    /// Returns an issuer's prepaid credit in `token`.
+    fn get_credit_balance(e: Env, issuer: Address, token: Address) -> i128;
+
+    /// Sponsors `beneficiary`'s fees up to `cap` in `token` until `expires_at` (sponsor-signed).
+    ///
+    /// This is an offer: it applies once the beneficiary calls `accept_sponsorship`. The
+    /// sponsor funds the agreement with a token allowance to this contract. It pays a fee only
+    /// when the remaining budget covers it in full and its transfer succeeds; otherwise the
+    /// issuer's credit or the issuer pays. An accepted, active agreement of another sponsor
+    /// cannot be replaced.
+    fn sponsor_issuer(
+        e: Env,
+        sponsor: Address,
+        beneficiary: Address,
+        token: Address,
+        cap: i128,
+        expires_at: u64,
+    );
+
+    /// Accepts the pending sponsorship offer of `sponsor` (beneficiary-signed).
+    fn accept_sponsorship(e: Env, beneficiary: Address, sponsor: Address);
+
+    /// Ends a sponsorship (signed by its sponsor or its beneficiary).
+    fn end_sponsorship(e: Env, caller: Address, beneficiary: Address);
+
+    /// Returns an issuer's sponsorship, if any.
+    fn get_sponsorship(e: Env, beneficiary: Address) -> Option<Sponsorship>;
+
+    /// Returns the budget left under an issuer's sponsorship (0 if none, pending or expired).
+    fn get_sponsorship_remaining(e: Env, beneficiary: Address) -> i128;
+
+    /// Returns the fee `issuer` would pay for its next issuance of `credential_type`.
+    fn quote_fee(e: Env, issuer: Address, credential_type: String) -> FeeQuote;
+
+    /// Sets or clears the price of an operation (admin or fee manager).
+    ///
+    /// Priced in the global fee token and charged while fees are enabled: `Push` to the origin
+    /// owner, `Share` to the sharing owner, `CreateVault` to the new owner,
+    /// `VerificationReceipt` to the verifier and `AccessRequest` to the requesting issuer. An
+    /// `Issue` price replaces `fee_amount` as the base issuance price. Unpriced operations are
+    /// free.
+    fn set_operation_fee(e: Env, caller: Address, operation: FeeOperation, amount: Option<i128>);
+
+    /// Returns the price of an operation, if set.
+    fn get_operation_fee(e: Env, operation: FeeOperation) -> Option<i128>;
+
+    /// Returns the cumulative fees charged to `account` in `token`, whoever paid them.
+    ///
+    /// Every charge also emits a `fee` event with the operation, token, amount and payer.
+    fn get_fees_charged(e: Env, account: Address, token: Address) -> i128;
+
+    /// Returns the cumulative fees `recipient` received in `token`.
+    fn get_fees_collected(e: Env, recipient: Address, token: Address) -> i128;
+
+    /// Sets or clears the refund policy (admin or fee manager).
+    ///
+    /// While set, issuance fees are held in escrow for `window` seconds (at most 7 days) and
+    /// refunded if the issuer retracts the VC with `retract_vc` in time. Each issuer receives at
+    /// most `max_refunds` refunds. Clearing it affects only later issuances.
+    fn set_refund_policy(e: Env, caller: Address, policy: Option<RefundPolicy>);
+
+    /// Returns the refund policy, if any.
+    fn get_refund_policy(e: Env) -> Option<RefundPolicy>;
+
+    /// Returns the escrowed fee payment of a VC, if any.
+    fn get_fee_payment(e: Env, vc_id: String) -> Option<FeePayment>;
+
+    /// Pays out escrowed fees whose window has elapsed, skipping the others (anyone may call).
+    ///
+    /// At most 25 IDs per call. Returns the number of payments settled.
+    fn settle_fees(e: Env, vc_ids: Vec<String>) -> u32;
+
     // -----------------------------
     // Vault (per owner)
     // -----------------------------
@@ -50,25 +210,133 @@ pub trait ActaTrait {
     /// Creates/initializes a vault for `owner`.
     ///
     /// - Default vault admin = `owner`.
-    /// - Sets DID URI metadata.
+    /// - Sets DID URI metadata (must be a `did:<method>:<id>` DID).
     /// - Initializes issuer list empty.
     fn create_vault(e: Env, owner: Address, did_uri: String);
 
     /// Sets the vault admin for `owner` (current vault admin-only).
     fn set_vault_admin(e: Env, owner: Address, new_admin: Address);
 
-    /// Adds/overwrites authorized issuer list (vault admin-only).
+    /// Adds issuers to the authorized set, ignoring duplicates (vault admin-only).
     fn authorize_issuers(e: Env, owner: Address, issuers: Vec<Address>);
 
+    /// Adds and removes issuers in one call (vault admin-only).
+    ///
+    /// Already authorized additions and unknown removals are skipped; the result lists
+    /// only the entries that changed. An address in both lists is rejected. The set is
+    /// capped at `MAX_VAULT_ISSUERS`.
+    fn update_issuers(
+        e: Env,
+        owner: Address,
+        add: Vec<Address>,
+        remove: Vec<Address>,
+    ) -> IssuerUpdate;
+
     /// Authorizes a single issuer (vault admin-only).
     fn authorize_issuer(e: Env, owner: Address, issuer: Address);
 
+    /// Authorizes a single issuer with limits (vault admin-only).
+    ///
+    /// - `valid_from` / `valid_until`: optional ledger timestamps (inclusive) bounding when
+    ///   the issuer may issue.
+    /// - `max_issuances`: optional cap on the number of VCs the issuer may issue into the vault.
+    /// - `credential_types`: credential types the issuer may issue (empty = any type).
+    fn authorize_issuer_with_limits(
+        e: Env,
+        owner: Address,
+        issuer: Address,
+        valid_from: Option<u64>,
+        valid_until: Option<u64>,
+        max_issuances: Option<u32>,
+        credential_types: Vec<String>,
+    );
+
+    /// Restricts an authorized issuer to `credential_types` (vault admin-only).
+    ///
+    /// An empty list lets the issuer issue any type.
+    fn set_issuer_credential_types(
+        e: Env,
+        owner: Address,
+        issuer: Address,
+        credential_types: Vec<String>,
+    );
+
+    /// Returns an authorized issuer's window and remaining allowance in a vault (public read).
+    fn get_issuer_allowance(e: Env, owner: Address, issuer: Address) -> IssuerAllowance;
+
+    /// Files an access request for `owner`'s vault (issuer-signed).
+    ///
+    /// - `credential_types`: requested scope (empty = any type).
+    /// - `expires_at`: ledger timestamp after which the request lapses if not approved, at most
+    ///   `MAX_REQUEST_HORIZON` (90 days) ahead.
+    /// - `access_period`: seconds the authorization lasts, counted from approval (`None` = no
+    ///   end).
+    ///
+    /// A new request from the same issuer replaces the pending one. Each filing is charged the
+    /// `AccessRequest` operation fee, so the 20 request slots of a vault cannot be filled for
+    /// free.
+    fn request_issuer_access(
+        e: Env,
+        issuer: Address,
+        owner: Address,
+        credential_types: Vec<String>,
+        expires_at: u64,
+        access_period: Option<u64>,
+    );
+
+    /// Lists pending, unexpired access requests of a vault (public read).
+    fn list_issuer_requests(e: Env, owner: Address) -> Vec<IssuerAccessRequest>;
+
+    /// Approves a pending request, authorizing the issuer for the requested scope and period
+    /// (vault admin-only).
+    fn approve_issuer_request(e: Env, owner: Address, issuer: Address);
+
+    /// Denies a pending request (vault admin-only).
+    fn deny_issuer_request(e: Env, owner: Address, issuer: Address);
+
+    /// Denies the listed issuers' requests, skipping unknown ones (vault admin-only).
+    ///
+    /// Returns the number of requests removed.
+    fn deny_issuer_requests(e: Env, owner: Address, issuers: Vec<Address>) -> u32;
+
+    /// Drops every access request of the vault, expired or not (vault admin-only).
+    ///
+    /// Returns the number of requests removed.
+    fn purge_issuer_requests(e: Env, owner: Address) -> u32;
+
     /// Revokes an authorized issuer (vault admin-only).
     fn revoke_issuer(e: Env, owner: Address, issuer: Address);
 
     /// Revokes the whole vault (vault admin-only). Blocks writes.
     fn revoke_vault(e: Env, owner: Address);
 
+    /// Lifts a vault revocation. `caller` must be the vault admin or the contract admin.
+    ///
+    /// Rejected while the vault is closing or either side of a rotation, and while an admin set
+    /// is configured (propose `RestoreVault` instead).
+    fn restore_vault(e: Env, caller: Address, owner: Address);
+
+    /// Moves a vault from `old_owner` to `new_owner` (vault admin and `new_owner` sign).
+    ///
+    /// Re-keys VCs in batches and updates their registry owner; call until `done`. Both vaults
+    /// are frozen meanwhile. A vault admin equal to `old_owner` becomes `new_owner`. Afterwards
+    /// read entrypoints given `old_owner` resolve to `new_owner`. Pending uploads are discarded.
+    fn rotate_vault_owner(e: Env, old_owner: Address, new_owner: Address) -> RotationProgress;
+
+    /// Closes a vault, deleting its VCs and issuer list in batches (vault admin-only).
+    ///
+    /// Call until `done`; the vault stays revoked meanwhile and no longer exists afterwards.
+    /// Deleted VCs that were `Valid` in the status registry become `Invalid`; their schema
+    /// reference, payload hash and commitment go with them, as do pending uploads. Rejected
+    /// while the vault is either side of a rotation.
+    fn close_vault(e: Env, owner: Address) -> CloseProgress;
+
+    /// Returns a vault's admin, DID, revoked flag and counts (public read).
+    fn get_vault(e: Env, owner: Address) -> VaultInfo;
+
+    /// Lists a vault's authorized issuers (public read).
+    fn list_issuers(e: Env, owner: Address) -> Vec<Address>;
+
     /// Lists VC IDs for the owner's vault.
     fn list_vc_ids(e: Env, owner: Address) -> Vec<String>;
 
@@ -83,9 +351,32 @@ pub trait ActaTrait {
     ///
     /// If the VC exists in the vault, the contract:
     /// - uses `vc.issuance_contract` and calls `verify(vc_id)` on it.
-    /// - if `issuance_contract` == this contract, it resolves locally.
+    /// - if `issuance_contract` == this contract, it resolves locally and also reports
+    ///   `issuer_did_verified` (`true`/`false`): whether the VC's issuer DID is currently
+    ///   bound to the address that issued it, and `issuer_accredited` (`true`/`false`):
+    ///   whether that address currently has a chain of trust to a root authority covering
+    ///   the VC's credential type, and `issuer_accreditation_chain`: that chain as
+    ///   comma-separated addresses, issuer first and root authority last (empty if none).
     fn verify_vc(e: Env, owner: Address, vc_id: String) -> Map<String, String>;
 
+    /// Verifies a VC as `verify_vc` does and stores a receipt of the result (verifier-signed).
+    ///
+    /// Charges the `VerificationReceipt` operation fee to the verifier, keeps the verifier's
+    /// latest receipt per VC and emits `("receipt", verifier)`.
+    fn record_verification(
+        e: Env,
+        verifier: Address,
+        owner: Address,
+        vc_id: String,
+    ) -> VerificationReceipt;
+
+    /// Returns a verifier's latest receipt for a VC, if any (public read).
+    fn get_verification_receipt(
+        e: Env,
+        verifier: Address,
+        vc_id: String,
+    ) -> Option<VerificationReceipt>;
+
     /// Push: moves a VC from one owner's vault to another.
     ///
     /// Requirements:
@@ -94,14 +385,130 @@ pub trait ActaTrait {
     /// - `issuer` must be authorized in `from_owner` vault (signature not required).
     fn push(e: Env, from_owner: Address, to_owner: Address, vc_id: String, issuer: Address);
 
+    /// Deletes a VC from the owner's vault (vault admin-only), e.g. for erasure requests.
+    ///
+    /// Works on revoked vaults. The schema reference, payload hash and commitment are removed;
+    /// the status registry is kept. A `("vc_del", owner)` event carrying the VC ID is emitted.
+    fn delete_vc(e: Env, owner: Address, vc_id: String);
+
+    /// Shares a VC with a verifier until `expires_at` (owner-signed; vault must be active).
+    ///
+    /// Replaces an earlier grant to the same verifier; at most 20 verifiers per VC. Charges the
+    /// `Share` operation fee to the owner and emits `("vc_share", owner)`. Shares are dropped
+    /// when the VC is pushed or deleted and follow it through an owner rotation.
+    fn share_vc(e: Env, owner: Address, vc_id: String, verifier: Address, expires_at: u64);
+
+    /// Withdraws a verifier's access to a VC (owner-signed).
+    fn unshare_vc(e: Env, owner: Address, vc_id: String, verifier: Address);
+
+    /// Returns the expiry of a verifier's access to a VC, unless it has lapsed (public read).
+    fn get_vc_share(e: Env, owner: Address, vc_id: String, verifier: Address) -> Option<u64>;
+
+    // -----------------------------
+    // M-of-N vault administration
+    // -----------------------------
+
+    /// Puts a vault under M-of-N control (vault admin-only, once).
+    ///
+    /// Afterwards issuer authorization and revocation (including limits, credential types and
+    /// request approval), `revoke_vault` and `set_vault_admin` are rejected when called
+    /// directly and go through proposals instead. `close_vault`, `rotate_vault_owner`,
+    /// `delete_vc` and `set_guardians` are rejected too; an executed `SetVaultAdmin` proposal
+    /// returns the vault to single-admin control first.
+    fn set_vault_admins(e: Env, owner: Address, admins: Vec<Address>, threshold: u32);
+
+    /// Returns the vault's admin set, if any (public read).
+    fn get_vault_admins(e: Env, owner: Address) -> Option<AdminSet>;
+
+    /// Proposes an admin action (admin set member-signed); counts as the first approval.
+    ///
+    /// Returns the proposal ID. Proposals lapse after `expires_at`.
+    fn propose_admin_action(
+        e: Env,
+        admin: Address,
+        owner: Address,
+        action: AdminAction,
+        expires_at: u64,
+    ) -> u32;
+
+    /// Approves a proposal (admin set member-signed); executes it at the threshold.
+    ///
+    /// Returns `true` if the proposal was executed.
+    fn approve_admin_action(e: Env, admin: Address, owner: Address, proposal_id: u32) -> bool;
+
+    /// Lists pending, unexpired proposals (public read).
+    fn list_admin_proposals(e: Env, owner: Address) -> Vec<Proposal>;
+
+    // -----------------------------
+    // Guardian recovery
+    // -----------------------------
+
+    /// Sets the vault's guardians, approval threshold and delay in seconds (vault admin-only).
+    ///
+    /// An empty list removes the guardians. The delay must be non-zero: it is the admin's
+    /// window to cancel. Any pending recovery is dropped.
+    fn set_guardians(
+        e: Env,
+        owner: Address,
+        guardians: Vec<Address>,
+        threshold: u32,
+        delay: u64,
+    );
+
+    /// Returns the vault's guardian configuration (public read).
+    fn get_guardians(e: Env, owner: Address) -> Option<GuardianConfig>;
+
+    /// Starts replacing the vault admin with `new_admin` (guardian-signed).
+    ///
+    /// Rejected while another recovery is pending, unless it failed to reach the threshold
+    /// within 7 days of being started.
+    fn initiate_recovery(e: Env, guardian: Address, owner: Address, new_admin: Address);
+
+    /// Approves the pending recovery towards `new_admin` (guardian-signed). Reaching the
+    /// threshold starts the delay; a recovery that lapsed below it fails with `Expired`.
+    fn approve_recovery(e: Env, guardian: Address, owner: Address, new_admin: Address);
+
+    /// Cancels the pending recovery before the threshold or during the delay (vault admin-only).
+    ///
+    /// Fails with `Expired` once the delay has elapsed.
+    fn cancel_recovery(e: Env, owner: Address);
+
+    /// Replaces the vault admin once the threshold is met and the delay elapsed (anyone).
+    ///
+    /// Clears the vault's admin set, if any, like `SetVaultAdmin`.
+    fn complete_recovery(e: Env, owner: Address);
+
+    /// Returns the pending recovery, if any (public read).
+    fn get_recovery(e: Env, owner: Address) -> Option<Recovery>;
+
     // -----------------------------
     // Issuance (status registry)
     // -----------------------------
 
+    /// Appends a part (at most 8 KiB) to the payload the issuer uploads for `owner`'s `vc_id`
+    /// (issuer-signed; issuer must be authorized in the vault). Returns the bytes uploaded so
+    /// far, capped by `max_payload_len`. `issue` with an empty `vc_data` consumes the upload.
+    /// Uploads expire 24 hours after their first part; at most 20 are pending per vault.
+    fn upload_vc_part(e: Env, issuer: Address, owner: Address, vc_id: String, part: Bytes) -> u32;
+
+    /// Discards a pending upload (issuer-signed).
+    fn cancel_vc_upload(e: Env, issuer: Address, owner: Address, vc_id: String);
+
+    /// Returns a pending upload, if any and not expired.
+    fn get_vc_upload(e: Env, owner: Address, vc_id: String) -> Option<PayloadUpload>;
+
     /// Issues a new VC:
+    /// - Rejects a `vc_id` that was issued before, even if since deleted (`VCAlreadyExists`).
+    /// - Requires `issuer_did` to be bound to `issuer` (see `bind_issuer_did`).
+    /// - Requires `credential_type` to be within the issuer's scope in the vault.
     /// - Stores payload in the owner's vault.
     /// - Stores status in this contract: `Valid`.
     /// - Records VC owner.
+    /// - Records the schema version issued against when `schema_id` is set (its latest version,
+    ///   which must not be deprecated).
+    /// - Records the SHA-256 of `vc_data` and the issuer's optional SHA-256 `commitment` of the
+    ///   plaintext credential (see `verify_commitment`).
+    /// - Issues the payload uploaded with `upload_vc_part` when `vc_data` is empty.
     ///
     /// Note: `vault_contract` is kept for backwards-compatibility but the unified contract
     /// always stores in its own vaults.
@@ -109,22 +516,178 @@ pub trait ActaTrait {
         e: Env,
         owner: Address,
         vc_id: String,
-        vc_data: String,
+        vc_data: Bytes,
         vault_contract: Address,
         issuer: Address,
         issuer_did: String,
+        credential_type: String,
+        schema_id: Option<String>,
+        commitment: Option<BytesN<32>>,
     ) -> String;
 
+    /// Returns the schema version a VC was issued against, if any.
+    fn get_vc_schema(e: Env, vc_id: String) -> Option<SchemaRef>;
+
+    /// Returns the SHA-256 of the payload a VC was issued with, if recorded.
+    fn get_vc_payload_hash(e: Env, vc_id: String) -> Option<BytesN<32>>;
+
+    /// Returns the issuer's SHA-256 commitment of a VC's plaintext, if supplied.
+    fn get_vc_commitment(e: Env, vc_id: String) -> Option<BytesN<32>>;
+
+    /// Returns `true` if `hash` is the SHA-256 of the ciphertext issued as `vc_id` or matches
+    /// the issuer's plaintext commitment. VCs issued without commitments always return `false`.
+    fn verify_commitment(e: Env, vc_id: String, hash: BytesN<32>) -> bool;
+
     /// Revokes a VC (owner-or-admin).
     fn revoke(e: Env, vc_id: String, date: String);
 
+    /// Retracts (revokes) a VC issued by mistake and refunds its escrowed fee (issuer-signed).
+    ///
+    /// Only within the refund window and the issuer's refund allowance. The fee goes back to
+    /// whoever paid it: the issuer, its sponsor, or its prepaid credit.
+    fn retract_vc(e: Env, issuer: Address, vc_id: String, date: String);
+
+    // -----------------------------
+    // DID registry
+    // -----------------------------
+
+    /// Registers a DID document controlled by `controller` (controller must sign).
+    ///
+    /// The DID must not be registered yet, and the controller must prove control of it: either
+    /// the DID is `did:pkh:stellar:<network>:<controller>`, or the contract admin attested the
+    /// controller with `attest_did_controller`. Verification methods are ed25519 public keys
+    /// (e.g. keys that issuers encrypt `vc_data` to).
+    fn register_did(
+        e: Env,
+        controller: Address,
+        did: String,
+        verification_methods: Vec<VerificationMethod>,
+        services: Vec<Service>,
+    );
+
+    /// Vouches that `controller` controls `did` so it can register the document (admin-only).
+    ///
+    /// Used for DID methods whose control cannot be proven on-chain (e.g. `did:web`).
+    fn attest_did_controller(e: Env, did: String, controller: Address);
+
+    /// Adds a verification method to a DID document (controller-only).
+    fn add_verification_method(e: Env, did: String, method: VerificationMethod);
+
+    /// Removes a verification method from a DID document (controller-only).
+    fn remove_verification_method(e: Env, did: String, method_id: String);
+
+    /// Rotates the public key of a verification method (controller-only).
+    fn rotate_did_key(e: Env, did: String, method_id: String, new_public_key: BytesN<32>);
+
+    /// Adds or replaces a service endpoint by ID (controller-only).
+    fn set_did_service(e: Env, did: String, service: Service);
+
+    /// Removes a service endpoint (controller-only).
+    fn remove_did_service(e: Env, did: String, service_id: String);
+
+    /// Transfers control of a DID document (current controller-only).
+    fn set_did_controller(e: Env, did: String, new_controller: Address);
+
+    /// Deactivates a DID document permanently (controller-only).
+    ///
+    /// The document still resolves, flagged as deactivated, but can no longer be updated.
+    fn deactivate_did(e: Env, did: String);
+
+    /// Resolves a DID to its document (public read).
+    fn resolve_did(e: Env, did: String) -> DidDocument;
+
+    // -----------------------------
+    // Issuer DID bindings
+    // -----------------------------
+
+    /// Binds the issuer's own `did:pkh:stellar:<network>:<issuer>` DID (issuer must sign).
+    ///
+    /// Any other DID method needs `attest_issuer_did`: control of e.g. a `did:web` cannot be
+    /// proven on-chain.
+    fn bind_issuer_did(e: Env, issuer: Address, did: String);
+
+    /// Binds `did` to `issuer` by attestation.
+    ///
+    /// The attester must sign and be the contract admin or an active accreditation body.
+    fn attest_issuer_did(e: Env, attester: Address, issuer: Address, did: String);
+
+    /// Removes a binding (issuer-only).
+    fn unbind_issuer_did(e: Env, issuer: Address, did: String);
+
+    /// Returns the binding between `issuer` and `did`, if any (public read).
+    fn get_issuer_did_binding(e: Env, issuer: Address, did: String) -> Option<IssuerDidBinding>;
+
+    // -----------------------------
+    // Trusted issuer registry
+    // -----------------------------
+
+    /// Registers (or re-registers) a root authority of the chain of trust (admin-only).
+    ///
+    /// `credential_types` limits what the authority can accredit; empty means all types.
+    /// Re-registering invalidates every accreditation issued under the previous record.
+    fn add_root_authority(e: Env, authority: Address, credential_types: Vec<String>);
+
+    /// Accredits `subject` for `credential_types` (accreditor must sign).
+    ///
+    /// The accreditor must be an active accreditation body and the types must be within its
+    /// own scope. `can_accredit` lets the subject accredit others in turn.
+    fn accredit(
+        e: Env,
+        accreditor: Address,
+        subject: Address,
+        credential_types: Vec<String>,
+        can_accredit: bool,
+    );
+
+    /// Revokes an accreditation (subject's accreditor or contract admin must sign).
+    ///
+    /// Cascades: every accreditation chaining through `subject` stops being valid.
+    fn revoke_accreditation(e: Env, revoker: Address, subject: Address);
+
+    /// Returns the accreditation record of `subject`, if any (public read).
+    fn get_accreditation(e: Env, subject: Address) -> Option<Accreditation>;
+
+    /// Checks whether `subject` is accredited (for `credential_type` if given) and returns its
+    /// chain of trust up to the root authority (public read).
+    fn verify_accreditation(
+        e: Env,
+        subject: Address,
+        credential_type: Option<String>,
+    ) -> AccreditationStatus;
+
+    // -----------------------------
+    // Credential schema registry
+    // -----------------------------
+
+    /// Publishes a version of a schema with the SHA-256 of its JSON document (author-signed).
+    ///
+    /// The first publisher of `schema_id` becomes its author; versions must increase.
+    fn publish_schema(e: Env, author: Address, schema_id: String, version: u32, hash: BytesN<32>);
+
+    /// Deprecates a schema version (author-signed). Deprecating the latest version blocks
+    /// issuance against the schema until a new version is published.
+    fn deprecate_schema(e: Env, author: Address, schema_id: String, version: u32);
+
+    /// Returns a schema version, or the latest one when `version` is `None`.
+    fn get_schema(e: Env, schema_id: String, version: Option<u32>) -> Schema;
+
     // -----------------------------
     // Migrations
     // -----------------------------
 
-    /// Migrates **legacy storage layouts**.
+    /// Migrates **legacy storage layouts** in bounded batches.
     ///
-    /// - If `owner` is `Some`, migrates vault legacy VCs for that owner.
+    /// - If `owner` is `Some`, migrates vault legacy VCs for that owner, then rewrites its string
+    ///   payloads as bytes (until then they are converted on read).
     /// - If `owner` is `None`, migrates legacy issuance status registry.
-    fn migrate(e: Env, owner: Option<Address>);
+    ///
+    /// Each call processes at most `MIGRATION_BATCH_SIZE` entries, popped off the end of the
+    /// legacy list, so it must be called repeatedly until the returned progress reports `done`.
+    /// Legacy keys are removed once the migration is complete.
+    fn migrate(e: Env, owner: Option<Address>) -> crate::migration::MigrationProgress;
+
+    /// Returns the progress of a legacy migration (public read).
+    ///
+    /// `owner` selects the same migration as in `migrate`.
+    fn migration_progress(e: Env, owner: Option<Address>) -> crate::migration::MigrationProgress;
 }
@@ -1,10 +1,30 @@
+use crate::accreditation::{Accreditation, AccreditationStatus};
 use crate::acta_trait::ActaTrait;
+use crate::did_registry::{DidDocument, Service, VerificationMethod};
+use crate::issuer::{IssuerAllowance, IssuerTerms, IssuerUpdate};
+use crate::issuer_did::IssuerDidBinding;
+use crate::issuer_request::IssuerAccessRequest;
+use crate::recovery::{GuardianConfig, Recovery};
+use crate::schema::{Schema, SchemaRef};
+use crate::sharing::VerificationReceipt;
 use crate::error::ContractError;
-use crate::{issuer, storage, vc_status, verifiable_credential};
+use crate::fee::{
+    FeeConfig, FeeOperation, FeePayment, FeeQuote, FeeShare, FeeTier, IssuerFee, RefundPolicy,
+    Sponsorship,
+};
+use crate::migration::MigrationProgress;
+use crate::validation::ValidationLimits;
+use crate::{
+    accreditation, did_registry, fee, issuer, issuer_did, issuer_request, migration, recovery,
+    schema, sharing, storage, validation, vault, vault_admins, vc_status, verifiable_credential,
+};
+use crate::vault::{CloseProgress, RotationProgress, VaultInfo};
+use crate::vault_admins::{AdminAction, AdminSet, Proposal};
 use crate::vc_status::VCStatus;
+use crate::verifiable_credential::PayloadUpload;
 use soroban_sdk::{
-    contract, contractimpl, contractmeta, panic_with_error, symbol_short, Address, BytesN, Env,
-    IntoVal, Map, String, Vec,
+    contract, contractimpl, contractmeta, panic_with_error, symbol_short, Address, Bytes, BytesN,
+    Env, IntoVal, Map, String, Vec,
 };
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -41,6 +61,8 @@ impl ActaTrait for ActaContract {
         if storage::has_contract_admin(&e) {
             panic_with_error!(e, ContractError::AlreadyInitialized);
         }
+        validation::validate_did(&e, &default_issuer_did);
+
         storage::write_contract_admin(&e, &contract_admin);
         storage::write_default_issuer_did(&e, &default_issuer_did);
 
@@ -63,23 +85,59 @@ impl ActaTrait for ActaContract {
     /// Parameters:
     /// - `token_contract`: Soroban token contract address used for charging.
     /// - `fee_dest`: destination address to receive fees.
-    /// - `fee_amount`: amount to transfer on each issuance/store (i128).
+    /// - `fee_amount`: default amount to transfer on each issuance (i128).
     fn set_fee_config(e: Env, token_contract: Address, fee_dest: Address, fee_amount: i128) {
         validate_contract_admin(&e);
+        validation::validate_fee_amount(&e, fee_amount);
+
         storage::write_fee_token_contract(&e, &token_contract);
         storage::write_fee_dest(&e, &fee_dest);
         storage::write_fee_amount(&e, &fee_amount);
     }
 
+    /// Split every fee across recipients (admin-only).
+    ///
+    /// Parameters:
+    /// - `shares`: recipients and basis points summing to 10000; empty sends fees to `fee_dest`.
+    fn set_fee_shares(e: Env, shares: Vec<FeeShare>) {
+        validate_contract_admin(&e);
+        fee::set_shares(&e, &shares);
+    }
+
+    /// Return the fee split (empty when every fee goes to `fee_dest`).
+    fn get_fee_shares(e: Env) -> Vec<FeeShare> {
+        storage::read_fee_shares(&e)
+    }
+
     /// Enable/disable fee charging (admin-only).
     ///
     /// Parameters:
     /// - `enabled`: `true` to charge fees on issuance, `false` otherwise.
     fn set_fee_enabled(e: Env, enabled: bool) {
         validate_contract_admin(&e);
+
+        // Enabling fees without a complete config would make every `issue` fail.
+        if enabled && !storage::has_fee_config(&e) {
+            panic_with_error!(e, ContractError::FeeNotConfigured)
+        }
         storage::write_fee_enabled(&e, &enabled);
     }
 
+    /// Configure input validation limits (admin-only).
+    ///
+    /// Parameters:
+    /// - `limits`: max ID length, max DID length and max payload size (bytes).
+    fn set_validation_limits(e: Env, limits: ValidationLimits) {
+        validate_contract_admin(&e);
+        validation::validate_limits(&e, &limits);
+        storage::write_validation_limits(&e, &limits);
+    }
+
+    /// Return the input validation limits currently enforced.
+    fn get_validation_limits(e: Env) -> ValidationLimits {
+        storage::read_validation_limits(&e)
+    }
+
     /// Upgrade contract WASM (admin-only).
     ///
     /// Parameters:
@@ -94,6 +152,260 @@ impl ActaTrait for ActaContract {
         String::from_str(&e, VERSION)
     }
 
+    /// Return the contract admin.
+    fn get_contract_admin(e: Env) -> Address {
+        storage::read_contract_admin(&e)
+    }
+
+    /// Return the default issuer DID, if the contract was initialized with one.
+    fn get_default_issuer_did(e: Env) -> Option<String> {
+        storage::read_default_issuer_did(&e)
+    }
+
+    /// Return the fee configuration, or `None` if it was never set.
+    fn get_fee_config(e: Env) -> Option<FeeConfig> {
+        fee::config(&e)
+    }
+
+    /// Set or clear the fee manager (admin-only).
+    ///
+    /// Parameters:
+    /// - `manager`: address allowed to manage the fee schedule, or `None` to remove it.
+    fn set_fee_manager(e: Env, manager: Option<Address>) {
+        validate_contract_admin(&e);
+        match manager {
+            Some(manager) => storage::write_fee_manager(&e, &manager),
+            None => storage::remove_fee_manager(&e),
+        }
+    }
+
+    /// Return the fee manager, if any.
+    fn get_fee_manager(e: Env) -> Option<Address> {
+        storage::read_fee_manager(&e)
+    }
+
+    /// Set or clear an issuer's fee override (admin or fee manager).
+    ///
+    /// Parameters:
+    /// - `caller`: contract admin or fee manager (must sign).
+    /// - `issuer_addr`: issuer address.
+    /// - `fee`: exemption, fixed amount and/or token; `None` removes the override.
+    fn set_issuer_fee(e: Env, caller: Address, issuer_addr: Address, fee: Option<IssuerFee>) {
+        fee::validate_fee_admin(&e, &caller);
+        fee::set_issuer_fee(&e, &issuer_addr, &fee);
+    }
+
+    /// Return an issuer's fee override, if any.
+    fn get_issuer_fee(e: Env, issuer_addr: Address) -> Option<IssuerFee> {
+        storage::read_issuer_fee(&e, &issuer_addr)
+    }
+
+    /// Set or clear the price of a credential type (admin or fee manager).
+    ///
+    /// Parameters:
+    /// - `caller`: contract admin or fee manager (must sign).
+    /// - `credential_type`: credential type.
+    /// - `amount`: amount in the global fee token, or `None` to use the global amount.
+    fn set_credential_type_fee(
+        e: Env,
+        caller: Address,
+        credential_type: String,
+        amount: Option<i128>,
+    ) {
+        fee::validate_fee_admin(&e, &caller);
+        validation::validate_id(&e, &credential_type);
+        fee::set_credential_type_fee(&e, &credential_type, &amount);
+    }
+
+    /// Replace the volume discount tiers (admin or fee manager).
+    ///
+    /// Parameters:
+    /// - `caller`: contract admin or fee manager (must sign).
+    /// - `tiers`: tiers with strictly increasing `min_issuances`.
+    fn set_fee_tiers(e: Env, caller: Address, tiers: Vec<FeeTier>) {
+        fee::validate_fee_admin(&e, &caller);
+        fee::set_tiers(&e, &tiers);
+    }
+
+    /// Return the volume discount tiers.
+    fn get_fee_tiers(e: Env) -> Vec<FeeTier> {
+        storage::read_fee_tiers(&e)
+    }
+
+    /// Deposit prepaid issuance credit into escrow (issuer-signed).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: issuer address.
+    /// - `token`: fee token to deposit.
+    /// - `amount`: amount to deposit (positive).
+    fn deposit_credit(e: Env, issuer_addr: Address, token: Address, amount: i128) {
+        issuer_addr.require_auth();
+        fee::deposit_credit(&e, &issuer_addr, &token, amount);
+    }
+
+    /// Withdraw unused issuance credit (issuer-signed).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: issuer address.
+    /// - `token`: fee token to withdraw.
+    /// - `amount`: amount to withdraw (positive, at most the balance).
+    fn withdraw_credit(e: Env, issuer_addr: Address, token: Address, amount: i128) {
+        issuer_addr.require_auth();
+        fee::withdraw_credit(&e, &issuer_addr, &token, amount);
+    }
+
+    /// Refund the remaining credit and close the credit account (issuer-signed).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: issuer address.
+    /// - `token`: fee token of the credit account.
+    fn close_credit(e: Env, issuer_addr: Address, token: Address) -> i128 {
+        issuer_addr.require_auth();
+        fee::close_credit(&e, &issuer_addr, &token)
+    }
+
+    /// Return an issuer's prepaid credit in a token (public read).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: issuer address.
+    /// - `token`: fee token.
+    fn get_credit_balance(e: Env, issuer_addr: Address, token: Address) -> i128 {
+        storage::read_fee_credit(&e, &issuer_addr, &token).unwrap_or(0)
+    }
+
+    /// Offer to sponsor an issuer's fees (sponsor-signed; applies once accepted).
+    ///
+    /// Parameters:
+    /// - `sponsor`: address paying the fees; must `approve` this contract on `token`.
+    /// - `beneficiary`: issuer whose fees are paid.
+    /// - `token`: fee token covered.
+    /// - `cap`: maximum total amount paid.
+    /// - `expires_at`: ledger timestamp after which the agreement no longer applies.
+    fn sponsor_issuer(
+        e: Env,
+        sponsor: Address,
+        beneficiary: Address,
+        token: Address,
+        cap: i128,
+        expires_at: u64,
+    ) {
+        sponsor.require_auth();
+        fee::sponsor(&e, &sponsor, &beneficiary, &token, cap, expires_at);
+    }
+
+    /// Accept a pending sponsorship offer (beneficiary-signed).
+    ///
+    /// Parameters:
+    /// - `beneficiary`: sponsored issuer.
+    /// - `sponsor`: sponsor whose offer is accepted.
+    fn accept_sponsorship(e: Env, beneficiary: Address, sponsor: Address) {
+        beneficiary.require_auth();
+        fee::accept_sponsorship(&e, &beneficiary, &sponsor);
+    }
+
+    /// End a sponsorship (sponsor- or beneficiary-signed).
+    ///
+    /// Parameters:
+    /// - `caller`: sponsor or beneficiary.
+    /// - `beneficiary`: sponsored issuer.
+    fn end_sponsorship(e: Env, caller: Address, beneficiary: Address) {
+        caller.require_auth();
+        fee::end_sponsorship(&e, &caller, &beneficiary);
+    }
+
+    /// Return an issuer's sponsorship, if any (public read).
+    ///
+    /// Parameters:
+    /// - `beneficiary`: sponsored issuer.
+    fn get_sponsorship(e: Env, beneficiary: Address) -> Option<Sponsorship> {
+        storage::read_sponsorship(&e, &beneficiary)
+    }
+
+    /// Return the budget left under an issuer's sponsorship (public read).
+    ///
+    /// Parameters:
+    /// - `beneficiary`: sponsored issuer.
+    fn get_sponsorship_remaining(e: Env, beneficiary: Address) -> i128 {
+        match storage::read_sponsorship(&e, &beneficiary) {
+            Some(sponsorship) => fee::remaining(&e, &sponsorship),
+            None => 0,
+        }
+    }
+
+    /// Quote the fee an issuer would pay for its next issuance (public read).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: issuer address.
+    /// - `credential_type`: credential type to issue.
+    fn quote_fee(e: Env, issuer_addr: Address, credential_type: String) -> FeeQuote {
+        fee::quote(&e, &issuer_addr, &credential_type)
+    }
+
+    /// Set or clear the price of an operation (admin or fee manager).
+    ///
+    /// Parameters:
+    /// - `caller`: contract admin or fee manager (must sign).
+    /// - `operation`: priced operation.
+    /// - `amount`: price in the global fee token; `None` makes the operation free again.
+    fn set_operation_fee(e: Env, caller: Address, operation: FeeOperation, amount: Option<i128>) {
+        fee::validate_fee_admin(&e, &caller);
+        fee::set_operation_fee(&e, operation, &amount);
+    }
+
+    /// Return the price of an operation, if set.
+    fn get_operation_fee(e: Env, operation: FeeOperation) -> Option<i128> {
+        storage::read_operation_fee(&e, operation)
+    }
+
+    /// Return the cumulative fees charged to an account (public read).
+    ///
+    /// Parameters:
+    /// - `account`: issuer or vault owner charged.
+    /// - `token`: fee token.
+    fn get_fees_charged(e: Env, account: Address, token: Address) -> i128 {
+        storage::read_fees_charged(&e, &account, &token)
+    }
+
+    /// Return the cumulative fees received by a destination (public read).
+    ///
+    /// Parameters:
+    /// - `recipient`: `fee_dest` or fee split recipient.
+    /// - `token`: fee token.
+    fn get_fees_collected(e: Env, recipient: Address, token: Address) -> i128 {
+        storage::read_fees_collected(&e, &recipient, &token)
+    }
+
+    /// Set or clear the refund policy for retracted issuances (admin or fee manager).
+    ///
+    /// Parameters:
+    /// - `caller`: contract admin or fee manager (must sign).
+    /// - `policy`: grace window and refund allowance; `None` stops escrowing new fees.
+    fn set_refund_policy(e: Env, caller: Address, policy: Option<RefundPolicy>) {
+        fee::validate_fee_admin(&e, &caller);
+        fee::set_refund_policy(&e, &policy);
+    }
+
+    /// Return the refund policy, if any.
+    fn get_refund_policy(e: Env) -> Option<RefundPolicy> {
+        storage::read_refund_policy(&e)
+    }
+
+    /// Return the escrowed fee payment of a VC, if any (public read).
+    ///
+    /// Parameters:
+    /// - `vc_id`: VC identifier.
+    fn get_fee_payment(e: Env, vc_id: String) -> Option<FeePayment> {
+        storage::read_fee_payment(&e, &vc_id)
+    }
+
+    /// Pay out escrowed fees whose refund window has elapsed (anyone may call).
+    ///
+    /// Parameters:
+    /// - `vc_ids`: VC identifiers whose payments to settle.
+    fn settle_fees(e: Env, vc_ids: Vec<String>) -> u32 {
+        fee::settle(&e, &vc_ids)
+    }
+
     // -----------------------------
     // Vault (per owner)
     // -----------------------------
@@ -118,11 +430,15 @@ impl ActaTrait for ActaContract {
         if storage::has_vault_admin(&e, &owner) {
             panic_with_error!(e, ContractError::AlreadyInitialized);
         }
+        validation::validate_did(&e, &did_uri);
 
         storage::write_vault_admin(&e, &owner, &owner);
         storage::write_vault_did(&e, &owner, &did_uri);
         storage::write_vault_revoked(&e, &owner, &false);
-        storage::write_vault_issuers(&e, &owner, &Vec::new(&e));
+        storage::write_vault_issuers(&e, &owner, &Map::new(&e));
+        storage::write_vault_bytes_layout(&e, &owner);
+
+        fee::charge_operation(&e, FeeOperation::CreateVault, &owner);
     }
 
     /// Set the per-vault admin (current vault admin must sign).
@@ -131,40 +447,198 @@ impl ActaTrait for ActaContract {
     /// - `owner`: vault owner address (selects which vault).
     /// - `new_admin`: new admin address for that vault.
     fn set_vault_admin(e: Env, owner: Address, new_admin: Address) {
-        validate_vault_admin(&e, &owner);
+        validate_vault_admin_direct(&e, &owner);
         validate_vault_active(&e, &owner);
         storage::write_vault_admin(&e, &owner, &new_admin);
     }
 
-    /// Replace the full authorized issuer list for a vault (vault admin-only).
+    /// Add issuers to a vault's authorized set, ignoring duplicates (vault admin-only).
     ///
     /// Parameters:
     /// - `owner`: vault owner address.
-    /// - `issuers`: list of issuer addresses allowed to issue into this vault.
+    /// - `issuers`: issuer addresses allowed to issue into this vault.
     fn authorize_issuers(e: Env, owner: Address, issuers: Vec<Address>) {
-        validate_vault_admin(&e, &owner);
+        validate_vault_admin_direct(&e, &owner);
         validate_vault_active(&e, &owner);
         issuer::authorize_issuers(&e, &owner, &issuers);
     }
 
+    /// Add and remove issuers in one call (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `add`: issuer addresses to authorize (already authorized ones are skipped).
+    /// - `remove`: issuer addresses to revoke (unknown ones are skipped).
+    fn update_issuers(
+        e: Env,
+        owner: Address,
+        add: Vec<Address>,
+        remove: Vec<Address>,
+    ) -> IssuerUpdate {
+        validate_vault_admin_direct(&e, &owner);
+        validate_vault_active(&e, &owner);
+        issuer::update_issuers(&e, &owner, &add, &remove)
+    }
+
     /// Add a single authorized issuer to a vault (vault admin-only).
     ///
     /// Parameters:
     /// - `owner`: vault owner address.
     /// - `issuer_addr`: issuer address to authorize.
     fn authorize_issuer(e: Env, owner: Address, issuer_addr: Address) {
-        validate_vault_admin(&e, &owner);
+        validate_vault_admin_direct(&e, &owner);
         validate_vault_active(&e, &owner);
         issuer::authorize_issuer(&e, &owner, &issuer_addr);
     }
 
+    /// Add a single authorized issuer with a time window and/or issuance cap (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `issuer_addr`: issuer address to authorize.
+    /// - `valid_from`: optional ledger timestamp from which the issuer may issue.
+    /// - `valid_until`: optional ledger timestamp until which the issuer may issue.
+    /// - `max_issuances`: optional maximum number of issuances into this vault.
+    /// - `credential_types`: credential types the issuer may issue (empty = any).
+    fn authorize_issuer_with_limits(
+        e: Env,
+        owner: Address,
+        issuer_addr: Address,
+        valid_from: Option<u64>,
+        valid_until: Option<u64>,
+        max_issuances: Option<u32>,
+        credential_types: Vec<String>,
+    ) {
+        validate_vault_admin_direct(&e, &owner);
+        validate_vault_active(&e, &owner);
+
+        let terms = IssuerTerms {
+            valid_from,
+            valid_until,
+            max_issuances,
+            credential_types,
+            issued: 0,
+        };
+        issuer::authorize_issuer_with_terms(&e, &owner, &issuer_addr, &terms);
+    }
+
+    /// Scope an authorized issuer to a set of credential types (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `issuer_addr`: authorized issuer address.
+    /// - `credential_types`: credential types the issuer may issue (empty = any).
+    fn set_issuer_credential_types(
+        e: Env,
+        owner: Address,
+        issuer_addr: Address,
+        credential_types: Vec<String>,
+    ) {
+        validate_vault_admin_direct(&e, &owner);
+        validate_vault_active(&e, &owner);
+        issuer::set_credential_types(&e, &owner, &issuer_addr, &credential_types);
+    }
+
+    /// Read an authorized issuer's window and remaining allowance (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `issuer_addr`: authorized issuer address.
+    fn get_issuer_allowance(e: Env, owner: Address, issuer_addr: Address) -> IssuerAllowance {
+        validate_vault_initialized(&e, &owner);
+        let issuers = storage::read_vault_issuers(&e, &owner);
+        if !issuer::is_authorized(&issuers, &issuer_addr) {
+            panic_with_error!(e, ContractError::IssuerNotAuthorized)
+        }
+        issuer::allowance(&e, &owner, &issuer_addr)
+    }
+
+    /// File an issuer access request for a vault (issuer-signed).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: requesting issuer address.
+    /// - `owner`: vault owner address.
+    /// - `credential_types`: requested scope (empty = any).
+    /// - `expires_at`: ledger timestamp after which the request lapses (at most
+    ///   `MAX_REQUEST_HORIZON` ahead).
+    /// - `access_period`: seconds the authorization lasts from approval (`None` = no end).
+    fn request_issuer_access(
+        e: Env,
+        issuer_addr: Address,
+        owner: Address,
+        credential_types: Vec<String>,
+        expires_at: u64,
+        access_period: Option<u64>,
+    ) {
+        issuer_addr.require_auth();
+        validate_vault_active(&e, &owner);
+        issuer_request::file(
+            &e,
+            &owner,
+            &issuer_addr,
+            &credential_types,
+            expires_at,
+            access_period,
+        );
+        fee::charge_operation(&e, FeeOperation::AccessRequest, &issuer_addr);
+    }
+
+    /// List pending, unexpired issuer access requests (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn list_issuer_requests(e: Env, owner: Address) -> Vec<IssuerAccessRequest> {
+        validate_vault_initialized(&e, &owner);
+        issuer_request::list(&e, &owner)
+    }
+
+    /// Approve a pending issuer access request (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `issuer_addr`: requesting issuer address.
+    fn approve_issuer_request(e: Env, owner: Address, issuer_addr: Address) {
+        validate_vault_admin_direct(&e, &owner);
+        validate_vault_active(&e, &owner);
+        issuer_request::approve(&e, &owner, &issuer_addr);
+    }
+
+    /// Deny a pending issuer access request (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `issuer_addr`: requesting issuer address.
+    fn deny_issuer_request(e: Env, owner: Address, issuer_addr: Address) {
+        validate_vault_admin_direct(&e, &owner);
+        issuer_request::deny(&e, &owner, &issuer_addr);
+    }
+
+    /// Deny several pending issuer access requests at once (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `issuers`: requesting issuer addresses (unknown ones are skipped).
+    fn deny_issuer_requests(e: Env, owner: Address, issuers: Vec<Address>) -> u32 {
+        validate_vault_admin_direct(&e, &owner);
+        issuer_request::deny_many(&e, &owner, &issuers)
+    }
+
+    /// Drop every issuer access request of a vault (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn purge_issuer_requests(e: Env, owner: Address) -> u32 {
+        validate_vault_admin_direct(&e, &owner);
+        issuer_request::purge(&e, &owner)
+    }
+
     /// Remove a single issuer from the authorized issuer list (vault admin-only).
     ///
     /// Parameters:
     /// - `owner`: vault owner address.
     /// - `issuer_addr`: issuer address to revoke.
     fn revoke_issuer(e: Env, owner: Address, issuer_addr: Address) {
-        validate_vault_admin(&e, &owner);
+        validate_vault_admin_direct(&e, &owner);
         validate_vault_active(&e, &owner);
         issuer::revoke_issuer(&e, &owner, &issuer_addr)
     }
@@ -174,16 +648,89 @@ impl ActaTrait for ActaContract {
     /// Parameters:
     /// - `owner`: vault owner address.
     fn revoke_vault(e: Env, owner: Address) {
-        validate_vault_admin(&e, &owner);
+        validate_vault_admin_direct(&e, &owner);
         validate_vault_active(&e, &owner);
         storage::write_vault_revoked(&e, &owner, &true);
     }
 
+    /// Restore a revoked vault (vault admin or contract admin; proposal-only under an admin set).
+    ///
+    /// Parameters:
+    /// - `caller`: vault admin or contract admin (must sign).
+    /// - `owner`: vault owner address.
+    fn restore_vault(e: Env, caller: Address, owner: Address) {
+        caller.require_auth();
+        validate_vault_initialized(&e, &owner);
+        if vault_admins::has_admin_set(&e, &owner) {
+            panic_with_error!(e, ContractError::Unauthorized)
+        }
+
+        let is_contract_admin =
+            storage::has_contract_admin(&e) && caller == storage::read_contract_admin(&e);
+        if caller != storage::read_vault_admin(&e, &owner) && !is_contract_admin {
+            panic_with_error!(e, ContractError::Unauthorized)
+        }
+        vault::restore(&e, &owner);
+    }
+
+    /// Move a vault to a new owner address in batches (vault admin and new owner sign).
+    ///
+    /// Parameters:
+    /// - `old_owner`: current vault owner address.
+    /// - `new_owner`: address that takes over the vault (must sign, must not own a vault).
+    fn rotate_vault_owner(e: Env, old_owner: Address, new_owner: Address) -> RotationProgress {
+        validate_vault_admin_direct(&e, &old_owner);
+        new_owner.require_auth();
+
+        match storage::read_vault_rotation(&e, &old_owner) {
+            Some(pending) => {
+                if pending != new_owner {
+                    panic_with_error!(e, ContractError::InvalidArgument)
+                }
+            }
+            None => {
+                validate_vault_active(&e, &old_owner);
+                vault::start_rotation(&e, &old_owner, &new_owner);
+            }
+        }
+        vault::rotate_batch(&e, &old_owner, &new_owner)
+    }
+
+    /// Close a vault, deleting its entries in batches (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn close_vault(e: Env, owner: Address) -> CloseProgress {
+        validate_vault_admin_direct(&e, &owner);
+        vault::close_batch(&e, &owner)
+    }
+
+    /// Read a vault's configuration (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn get_vault(e: Env, owner: Address) -> VaultInfo {
+        let owner = vault::resolve(&e, &owner);
+        validate_vault_initialized(&e, &owner);
+        vault::info(&e, &owner)
+    }
+
+    /// List a vault's authorized issuers (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn list_issuers(e: Env, owner: Address) -> Vec<Address> {
+        let owner = vault::resolve(&e, &owner);
+        validate_vault_initialized(&e, &owner);
+        vault::issuers(&e, &owner)
+    }
+
     /// List VC IDs stored in a vault.
     ///
     /// Parameters:
     /// - `owner`: vault owner address.
     fn list_vc_ids(e: Env, owner: Address) -> Vec<String> {
+        let owner = vault::resolve(&e, &owner);
         storage::read_vault_vc_ids(&e, &owner)
     }
 
@@ -197,6 +744,7 @@ impl ActaTrait for ActaContract {
         owner: Address,
         vc_id: String,
     ) -> Option<verifiable_credential::VerifiableCredential> {
+        let owner = vault::resolve(&e, &owner);
         storage::read_vault_vc(&e, &owner, &vc_id)
     }
 
@@ -206,27 +754,96 @@ impl ActaTrait for ActaContract {
     /// - `owner`: vault owner address (used only to check that the VC exists in that vault).
     /// - `vc_id`: VC identifier.
     fn verify_vc(e: Env, owner: Address, vc_id: String) -> Map<String, String> {
+        let owner = vault::resolve(&e, &owner);
         // if not present in vault => invalid
-        let vc_opt = storage::read_vault_vc(&e, &owner, &vc_id);
-        if vc_opt.is_none() {
-            return issuance_status_to_map(&e, VCStatus::Invalid);
-        }
+        let vc = match storage::read_vault_vc(&e, &owner, &vc_id) {
+            Some(vc) => vc,
+            None => return issuance_status_to_map(&e, VCStatus::Invalid),
+        };
 
-        let vc = vc_opt.unwrap();
-        let issuance_contract = vc.issuance_contract;
+        let issuance_contract = vc.issuance_contract.clone();
 
         // If issuance contract is this contract, resolve locally.
         if issuance_contract == e.current_contract_address() {
             let status = storage::read_vc_status(&e, &vc_id);
-            return issuance_status_to_map(&e, status);
+            let mut m = issuance_status_to_map(&e, status);
+
+            let (did_verified, accreditation) = match storage::read_vc_issuer(&e, &vc_id) {
+                Some(issuer_addr) => (
+                    issuer_did::is_bound(&e, &issuer_addr, &vc.issuer_did),
+                    accreditation::status(&e, &issuer_addr, &credential_type_filter(&vc)),
+                ),
+                None => (
+                    false,
+                    AccreditationStatus {
+                        accredited: false,
+                        chain: Vec::new(&e),
+                    },
+                ),
+            };
+            m.set(
+                String::from_str(&e, "issuer_did_verified"),
+                bool_to_string(&e, did_verified),
+            );
+            m.set(
+                String::from_str(&e, "issuer_accredited"),
+                bool_to_string(&e, accreditation.accredited),
+            );
+            m.set(
+                String::from_str(&e, "issuer_accreditation_chain"),
+                chain_to_string(&e, &accreditation.chain),
+            );
+            return m;
         }
 
         // Otherwise, delegate to the external issuance contract's `verify(vc_id)`.
-        e.invoke_contract::<Map<String, String>>(
+        match e.try_invoke_contract::<Map<String, String>, soroban_sdk::Error>(
             &issuance_contract,
             &symbol_short!("verify"),
             (vc_id,).into_val(&e),
-        )
+        ) {
+            Ok(Ok(status)) => status,
+            _ => panic_with_error!(e, ContractError::IssuanceContractCallFailed),
+        }
+    }
+
+    /// Verify a VC and store a receipt of the result (verifier-signed).
+    ///
+    /// Parameters:
+    /// - `verifier`: verifier address (must sign; pays the receipt fee).
+    /// - `owner`: vault owner address (rotated addresses resolve).
+    /// - `vc_id`: VC identifier.
+    fn record_verification(
+        e: Env,
+        verifier: Address,
+        owner: Address,
+        vc_id: String,
+    ) -> VerificationReceipt {
+        verifier.require_auth();
+        validation::validate_id(&e, &vc_id);
+
+        let owner = vault::resolve(&e, &owner);
+        let result = Self::verify_vc(e.clone(), owner.clone(), vc_id.clone());
+        let status = result
+            .get(String::from_str(&e, "status"))
+            .unwrap_or_else(|| String::from_str(&e, "invalid"));
+        let receipt = sharing::record_receipt(&e, &verifier, &owner, &vc_id, &status);
+
+        fee::charge_operation(&e, FeeOperation::VerificationReceipt, &verifier);
+        receipt
+    }
+
+    /// Return a verifier's latest receipt for a VC (public read).
+    ///
+    /// Parameters:
+    /// - `verifier`: verifier address.
+    /// - `vc_id`: VC identifier.
+    fn get_verification_receipt(
+        e: Env,
+        verifier: Address,
+        vc_id: String,
+    ) -> Option<VerificationReceipt> {
+        storage::read_verification_receipt(&e, &verifier, &vc_id)
     }
 
     /// Move a VC from one owner's vault to another.
@@ -244,44 +861,292 @@ impl ActaTrait for ActaContract {
 
         // Only the origin owner signs.
         from_owner.require_auth();
+        validation::validate_id(&e, &vc_id);
 
         // Issuer must be authorized in origin vault (signature not required).
         validate_issuer_authorized_only(&e, &from_owner, &issuer_addr);
 
-        let vc_opt = storage::read_vault_vc(&e, &from_owner, &vc_id);
-        if vc_opt.is_none() {
-            panic_with_error!(e, ContractError::VCNotFound);
-        }
-        let vc = vc_opt.unwrap();
+        let vc = match storage::read_vault_vc(&e, &from_owner, &vc_id) {
+            Some(vc) => vc,
+            None => panic_with_error!(e, ContractError::VCNotFound),
+        };
 
+        // Shares were granted by the origin owner and do not follow the VC.
         storage::remove_vault_vc(&e, &from_owner, &vc_id);
+        storage::remove_vc_shares(&e, &from_owner, &vc_id);
         storage::remove_vault_vc_id(&e, &from_owner, &vc_id);
 
         storage::write_vault_vc(&e, &to_owner, &vc_id, &vc);
         storage::append_vault_vc_id(&e, &to_owner, &vc_id);
+
+        fee::charge_operation(&e, FeeOperation::Push, &from_owner);
+    }
+
+    /// Delete a VC from a vault (vault admin-only). Keeps the status registry entry.
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `vc_id`: VC identifier.
+    fn delete_vc(e: Env, owner: Address, vc_id: String) {
+        validate_vault_admin_direct(&e, &owner);
+        verifiable_credential::delete_vc(&e, &owner, &vc_id);
+    }
+
+    /// Share a VC with a verifier (owner-signed).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address (must sign; pays the share fee).
+    /// - `vc_id`: VC identifier.
+    /// - `verifier`: verifier address granted access.
+    /// - `expires_at`: ledger timestamp until which the grant holds (must be in the future).
+    fn share_vc(e: Env, owner: Address, vc_id: String, verifier: Address, expires_at: u64) {
+        owner.require_auth();
+        validate_vault_active(&e, &owner);
+        validation::validate_id(&e, &vc_id);
+
+        sharing::share(&e, &owner, &vc_id, &verifier, expires_at);
+        fee::charge_operation(&e, FeeOperation::Share, &owner);
+    }
+
+    /// Withdraw a verifier's access to a VC (owner-signed).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address (must sign).
+    /// - `vc_id`: VC identifier.
+    /// - `verifier`: verifier address.
+    fn unshare_vc(e: Env, owner: Address, vc_id: String, verifier: Address) {
+        owner.require_auth();
+        sharing::unshare(&e, &owner, &vc_id, &verifier);
+    }
+
+    /// Return the expiry of a verifier's access to a VC (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address (rotated addresses resolve).
+    /// - `vc_id`: VC identifier.
+    /// - `verifier`: verifier address.
+    fn get_vc_share(e: Env, owner: Address, vc_id: String, verifier: Address) -> Option<u64> {
+        let owner = vault::resolve(&e, &owner);
+        sharing::share_expiry(&e, &owner, &vc_id, &verifier)
+    }
+
+    // -----------------------------
+    // M-of-N vault administration
+    // -----------------------------
+
+    /// Put a vault under M-of-N control (vault admin-only, once).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `admins`: administrator addresses.
+    /// - `threshold`: approvals required to execute a proposal.
+    fn set_vault_admins(e: Env, owner: Address, admins: Vec<Address>, threshold: u32) {
+        validate_vault_admin_direct(&e, &owner);
+        validate_vault_active(&e, &owner);
+        vault_admins::set_admins(&e, &owner, &admins, threshold);
+    }
+
+    /// Read the vault's admin set (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn get_vault_admins(e: Env, owner: Address) -> Option<AdminSet> {
+        validate_vault_initialized(&e, &owner);
+        storage::read_vault_admin_set(&e, &owner)
+    }
+
+    /// Propose an admin action (admin set member-signed). Returns the proposal ID.
+    ///
+    /// Parameters:
+    /// - `admin`: proposing administrator.
+    /// - `owner`: vault owner address.
+    /// - `action`: action to execute once approved.
+    /// - `expires_at`: ledger timestamp after which the proposal lapses.
+    fn propose_admin_action(
+        e: Env,
+        admin: Address,
+        owner: Address,
+        action: AdminAction,
+        expires_at: u64,
+    ) -> u32 {
+        admin.require_auth();
+        validate_vault_initialized(&e, &owner);
+        vault_admins::propose(&e, &owner, &admin, &action, expires_at)
+    }
+
+    /// Approve a pending proposal (admin set member-signed). Returns `true` once executed.
+    ///
+    /// Parameters:
+    /// - `admin`: approving administrator.
+    /// - `owner`: vault owner address.
+    /// - `proposal_id`: proposal identifier.
+    fn approve_admin_action(e: Env, admin: Address, owner: Address, proposal_id: u32) -> bool {
+        admin.require_auth();
+        validate_vault_initialized(&e, &owner);
+        vault_admins::approve(&e, &owner, &admin, proposal_id)
+    }
+
+    /// List pending, unexpired proposals (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn list_admin_proposals(e: Env, owner: Address) -> Vec<Proposal> {
+        validate_vault_initialized(&e, &owner);
+        vault_admins::list(&e, &owner)
+    }
+
+    // -----------------------------
+    // Guardian recovery
+    // -----------------------------
+
+    /// Configure the vault's guardians (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    /// - `guardians`: guardian addresses (empty removes them).
+    /// - `threshold`: approvals required to recover.
+    /// - `delay`: seconds between reaching the threshold and the admin change (non-zero).
+    fn set_guardians(
+        e: Env,
+        owner: Address,
+        guardians: Vec<Address>,
+        threshold: u32,
+        delay: u64,
+    ) {
+        validate_vault_admin_direct(&e, &owner);
+        recovery::set_guardians(&e, &owner, &guardians, threshold, delay);
+    }
+
+    /// Read the vault's guardian configuration (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn get_guardians(e: Env, owner: Address) -> Option<GuardianConfig> {
+        validate_vault_initialized(&e, &owner);
+        storage::read_guardians(&e, &owner)
+    }
+
+    /// Start a vault admin recovery (guardian-signed).
+    ///
+    /// Parameters:
+    /// - `guardian`: guardian address.
+    /// - `owner`: vault owner address.
+    /// - `new_admin`: proposed vault admin.
+    fn initiate_recovery(e: Env, guardian: Address, owner: Address, new_admin: Address) {
+        guardian.require_auth();
+        validate_vault_initialized(&e, &owner);
+        recovery::initiate(&e, &owner, &guardian, &new_admin);
+    }
+
+    /// Approve the pending recovery (guardian-signed).
+    ///
+    /// Parameters:
+    /// - `guardian`: guardian address.
+    /// - `owner`: vault owner address.
+    /// - `new_admin`: vault admin the pending recovery must propose.
+    fn approve_recovery(e: Env, guardian: Address, owner: Address, new_admin: Address) {
+        guardian.require_auth();
+        validate_vault_initialized(&e, &owner);
+        recovery::approve(&e, &owner, &guardian, &new_admin);
+    }
+
+    /// Cancel the pending recovery (vault admin-only).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn cancel_recovery(e: Env, owner: Address) {
+        validate_vault_admin_direct(&e, &owner);
+        recovery::cancel(&e, &owner);
+    }
+
+    /// Complete a recovery whose delay has elapsed (anyone may call).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn complete_recovery(e: Env, owner: Address) {
+        validate_vault_initialized(&e, &owner);
+        recovery::complete(&e, &owner);
+    }
+
+    /// Read the pending recovery (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner address.
+    fn get_recovery(e: Env, owner: Address) -> Option<Recovery> {
+        validate_vault_initialized(&e, &owner);
+        storage::read_recovery(&e, &owner)
     }
 
     // -----------------------------
     // Issuance
     // -----------------------------
 
+    /// Upload part of a large payload ahead of `issue` (issuer-signed).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: issuer address (must sign and be authorized in owner's vault).
+    /// - `owner`: vault owner that will receive the VC.
+    /// - `vc_id`: VC identifier the payload is for.
+    /// - `part`: next part of the payload (at most `PAYLOAD_CHUNK_SIZE` bytes).
+    fn upload_vc_part(
+        e: Env,
+        issuer_addr: Address,
+        owner: Address,
+        vc_id: String,
+        part: Bytes,
+    ) -> u32 {
+        issuer_addr.require_auth();
+        validate_vault_active(&e, &owner);
+        validate_vault_initialized(&e, &owner);
+        validate_issuer_authorized_only(&e, &owner, &issuer_addr);
+        validation::validate_id(&e, &vc_id);
+
+        verifiable_credential::upload_part(&e, &owner, &issuer_addr, &vc_id, &part)
+    }
+
+    /// Discard a pending upload (issuer-signed).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: issuer that started the upload.
+    /// - `owner`: vault owner.
+    /// - `vc_id`: VC identifier.
+    fn cancel_vc_upload(e: Env, issuer_addr: Address, owner: Address, vc_id: String) {
+        issuer_addr.require_auth();
+        verifiable_credential::cancel_upload(&e, &owner, &issuer_addr, &vc_id);
+    }
+
+    /// Return a pending upload that has not expired (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: vault owner.
+    /// - `vc_id`: VC identifier.
+    fn get_vc_upload(e: Env, owner: Address, vc_id: String) -> Option<PayloadUpload> {
+        verifiable_credential::pending_upload(&e, &owner, &vc_id)
+    }
+
     /// Issue a VC: stores payload in vault + writes issuance status = `valid`.
     ///
     /// Parameters:
     /// - `owner`: vault owner that will receive the VC.
     /// - `vc_id`: VC identifier (application-defined).
-    /// - `vc_data`: VC payload (ciphertext only).
+    /// - `vc_data`: VC payload (ciphertext only); empty to issue the payload uploaded in parts.
     /// - `vault_contract`: kept for backwards-compat; must be this contract.
     /// - `issuer_addr`: issuer address (must sign and be authorized in owner's vault).
-    /// - `issuer_did`: issuer DID metadata.
+    /// - `issuer_did`: issuer DID (must be bound to `issuer_addr`).
+    /// - `credential_type`: credential type (must be within the issuer's scope in the vault).
+    /// - `schema_id`: optional schema the VC conforms to (latest version, not deprecated).
+    /// - `commitment`: optional SHA-256 of the plaintext credential.
     fn issue(
         e: Env,
         owner: Address,
         vc_id: String,
-        vc_data: String,
+        vc_data: Bytes,
         vault_contract: Address,
         issuer_addr: Address,
         issuer_did: String,
+        credential_type: String,
+        schema_id: Option<String>,
+        commitment: Option<BytesN<32>>,
     ) -> String {
         // Require issuer signature once (avoid double-auth when calling local vault).
         issuer_addr.require_auth();
@@ -293,30 +1158,84 @@ impl ActaTrait for ActaContract {
             panic_with_error!(e, ContractError::InvalidVaultContract);
         }
 
+        validation::validate_id(&e, &vc_id);
+        verifiable_credential::validate_unused_id(&e, &vc_id);
+        let vc_data = if vc_data.is_empty() {
+            verifiable_credential::take_upload(&e, &owner, &issuer_addr, &vc_id).unwrap_or(vc_data)
+        } else {
+            vc_data
+        };
+        validation::validate_payload(&e, &vc_data);
+        validation::validate_did(&e, &issuer_did);
+        validation::validate_id(&e, &credential_type);
+
         // Local vault path:
         // - issuer already signed above
         // - we still must ensure issuer is authorized for the owner's vault
         validate_vault_active(&e, &owner);
         validate_vault_initialized(&e, &owner);
         validate_issuer_authorized_only(&e, &owner, &issuer_addr);
-
-        store_vc_payload(
-            &e,
-            &owner,
-            vc_id.clone(),
-            vc_data,
-            &issuer_addr,
+        issuer::validate_within_terms(&e, &owner, &issuer_addr);
+        issuer::validate_credential_type(&e, &owner, &issuer_addr, &credential_type);
+        issuer_did::validate_bound(&e, &issuer_addr, &issuer_did);
+        let schema = schema_id.map(|id| schema::resolve_for_issuance(&e, &id));
+
+        let vc = verifiable_credential::VerifiableCredential {
+            id: vc_id.clone(),
+            data: vc_data,
+            issuance_contract: this.clone(),
             issuer_did,
-            this.clone(),
-        );
+            credential_type,
+        };
+        store_vc_payload(&e, &owner, &issuer_addr, &vc);
+        verifiable_credential::commit(&e, &vc, &commitment);
 
         // Update status registry in this contract.
         storage::write_vc_status(&e, &vc_id, &VCStatus::Valid);
         storage::write_vc_owner(&e, &vc_id, &owner);
+        storage::write_vc_issuer(&e, &vc_id, &issuer_addr);
+        if let Some(schema) = schema {
+            storage::write_vc_schema(&e, &vc_id, &schema);
+        }
+        issuer::record_issuance(&e, &owner, &issuer_addr);
 
         vc_id
     }
 
+    /// Return the schema version a VC was issued against (public read).
+    ///
+    /// Parameters:
+    /// - `vc_id`: VC identifier.
+    fn get_vc_schema(e: Env, vc_id: String) -> Option<SchemaRef> {
+        storage::read_vc_schema(&e, &vc_id)
+    }
+
+    /// Return the SHA-256 of the payload a VC was issued with (public read).
+    ///
+    /// Parameters:
+    /// - `vc_id`: VC identifier.
+    fn get_vc_payload_hash(e: Env, vc_id: String) -> Option<BytesN<32>> {
+        storage::read_vc_payload_hash(&e, &vc_id)
+    }
+
+    /// Return the issuer's plaintext commitment of a VC (public read).
+    ///
+    /// Parameters:
+    /// - `vc_id`: VC identifier.
+    fn get_vc_commitment(e: Env, vc_id: String) -> Option<BytesN<32>> {
+        storage::read_vc_commitment(&e, &vc_id)
+    }
+
+    /// Check a hash against the commitments of a VC (public read).
+    ///
+    /// Parameters:
+    /// - `vc_id`: VC identifier.
+    /// - `hash`: SHA-256 of the presented ciphertext or of the decrypted credential.
+    fn verify_commitment(e: Env, vc_id: String, hash: BytesN<32>) -> bool {
+        validate_vc_exists(&e, &vc_id);
+        verifiable_credential::verify_commitment(&e, &vc_id, &hash)
+    }
+
     /// Revoke a VC by ID.
     ///
     /// Parameters:
@@ -336,63 +1255,322 @@ impl ActaTrait for ActaContract {
         vc_status::revoke_vc(&e, vc_id, date);
     }
 
+    /// Retract a VC issued by mistake and refund its fee (issuer-signed, within the window).
+    ///
+    /// Parameters:
+    /// - `issuer_addr`: issuer charged for the VC.
+    /// - `vc_id`: VC identifier.
+    /// - `date`: revocation date string (recommended ISO-8601).
+    fn retract_vc(e: Env, issuer_addr: Address, vc_id: String, date: String) {
+        issuer_addr.require_auth();
+        validate_vc_exists(&e, &vc_id);
+
+        fee::refund(&e, &issuer_addr, &vc_id);
+        vc_status::revoke_vc(&e, vc_id, date);
+    }
+
+    // -----------------------------
+    // DID registry
+    // -----------------------------
+
+    /// Register a DID document.
+    ///
+    /// Parameters:
+    /// - `controller`: address managing the document (must sign).
+    /// - `did`: DID to register (`did:<method>:<id>`).
+    /// - `verification_methods`: initial ed25519 public keys.
+    /// - `services`: initial service endpoints.
+    fn register_did(
+        e: Env,
+        controller: Address,
+        did: String,
+        verification_methods: Vec<VerificationMethod>,
+        services: Vec<Service>,
+    ) {
+        controller.require_auth();
+        validation::validate_did(&e, &did);
+        did_registry::register(&e, &did, &controller, &verification_methods, &services);
+    }
+
+    /// Attest that an address controls a DID (contract admin-only).
+    ///
+    /// Parameters:
+    /// - `did`: DID whose control cannot be proven on-chain.
+    /// - `controller`: address allowed to register its document.
+    fn attest_did_controller(e: Env, did: String, controller: Address) {
+        validate_contract_admin(&e);
+        validation::validate_did(&e, &did);
+        storage::write_did_attestation(&e, &did, &controller);
+    }
+
+    /// Add a verification method (controller-only).
+    ///
+    /// Parameters:
+    /// - `did`: DID document to update.
+    /// - `method`: new verification method (ID must be unused).
+    fn add_verification_method(e: Env, did: String, method: VerificationMethod) {
+        let mut doc = validate_did_controller(&e, &did);
+        did_registry::add_verification_method(&e, &mut doc, &method);
+        did_registry::write(&e, &mut doc);
+    }
+
+    /// Remove a verification method (controller-only).
+    ///
+    /// Parameters:
+    /// - `did`: DID document to update.
+    /// - `method_id`: ID of the method to remove.
+    fn remove_verification_method(e: Env, did: String, method_id: String) {
+        let mut doc = validate_did_controller(&e, &did);
+        did_registry::remove_verification_method(&e, &mut doc, &method_id);
+        did_registry::write(&e, &mut doc);
+    }
+
+    /// Rotate the public key of a verification method (controller-only).
+    ///
+    /// Parameters:
+    /// - `did`: DID document to update.
+    /// - `method_id`: ID of the method to rotate.
+    /// - `new_public_key`: replacement ed25519 public key.
+    fn rotate_did_key(e: Env, did: String, method_id: String, new_public_key: BytesN<32>) {
+        let mut doc = validate_did_controller(&e, &did);
+        did_registry::rotate_key(&e, &mut doc, &method_id, &new_public_key);
+        did_registry::write(&e, &mut doc);
+    }
+
+    /// Add or replace a service endpoint (controller-only).
+    ///
+    /// Parameters:
+    /// - `did`: DID document to update.
+    /// - `service`: service to add, replacing any service with the same ID.
+    fn set_did_service(e: Env, did: String, service: Service) {
+        let mut doc = validate_did_controller(&e, &did);
+        did_registry::set_service(&e, &mut doc, &service);
+        did_registry::write(&e, &mut doc);
+    }
+
+    /// Remove a service endpoint (controller-only).
+    ///
+    /// Parameters:
+    /// - `did`: DID document to update.
+    /// - `service_id`: ID of the service to remove.
+    fn remove_did_service(e: Env, did: String, service_id: String) {
+        let mut doc = validate_did_controller(&e, &did);
+        did_registry::remove_service(&e, &mut doc, &service_id);
+        did_registry::write(&e, &mut doc);
+    }
+
+    /// Transfer control of a DID document (current controller-only).
+    ///
+    /// Parameters:
+    /// - `did`: DID document to update.
+    /// - `new_controller`: new controller address.
+    fn set_did_controller(e: Env, did: String, new_controller: Address) {
+        let mut doc = validate_did_controller(&e, &did);
+        doc.controller = new_controller;
+        did_registry::write(&e, &mut doc);
+    }
+
+    /// Deactivate a DID document (controller-only). Irreversible.
+    ///
+    /// Parameters:
+    /// - `did`: DID document to deactivate.
+    fn deactivate_did(e: Env, did: String) {
+        let mut doc = validate_did_controller(&e, &did);
+        doc.deactivated = true;
+        did_registry::write(&e, &mut doc);
+    }
+
+    /// Resolve a DID document (public read).
+    ///
+    /// Parameters:
+    /// - `did`: DID to resolve.
+    fn resolve_did(e: Env, did: String) -> DidDocument {
+        did_registry::resolve(&e, &did)
+    }
+
+    // -----------------------------
+    // Issuer DID bindings
+    // -----------------------------
+
+    /// Bind the issuer's own `did:pkh:stellar` DID to it.
+    ///
+    /// Parameters:
+    /// - `issuer`: issuer address (must sign; the signature proves control of the DID).
+    /// - `did`: `did:pkh:stellar:<network>:<issuer>`.
+    fn bind_issuer_did(e: Env, issuer: Address, did: String) {
+        issuer.require_auth();
+        validation::validate_did(&e, &did);
+        issuer_did::bind_own(&e, &issuer, &did);
+    }
+
+    /// Bind a DID to an issuer by attestation.
+    ///
+    /// Parameters:
+    /// - `attester`: contract admin or active accreditation body (must sign).
+    /// - `issuer`: issuer address.
+    /// - `did`: DID the attester vouches the issuer controls.
+    fn attest_issuer_did(e: Env, attester: Address, issuer: Address, did: String) {
+        attester.require_auth();
+        if attester != storage::read_contract_admin(&e)
+            && !accreditation::is_accreditor(&e, &attester)
+        {
+            panic_with_error!(e, ContractError::Unauthorized)
+        }
+        validation::validate_did(&e, &did);
+        issuer_did::bind_attested(&e, &issuer, &did, &attester);
+    }
+
+    /// Remove a DID binding (issuer-only).
+    ///
+    /// Parameters:
+    /// - `issuer`: issuer address (must sign).
+    /// - `did`: bound DID.
+    fn unbind_issuer_did(e: Env, issuer: Address, did: String) {
+        issuer.require_auth();
+        issuer_did::unbind(&e, &issuer, &did);
+    }
+
+    /// Read the binding between an issuer and a DID (public read).
+    ///
+    /// Parameters:
+    /// - `issuer`: issuer address.
+    /// - `did`: DID.
+    fn get_issuer_did_binding(e: Env, issuer: Address, did: String) -> Option<IssuerDidBinding> {
+        storage::read_issuer_did_binding(&e, &issuer, &did)
+    }
+
+    // -----------------------------
+    // Trusted issuer registry
+    // -----------------------------
+
+    /// Register a root authority (admin-only).
+    ///
+    /// Parameters:
+    /// - `authority`: root accreditation body.
+    /// - `credential_types`: types it may accredit (empty = all).
+    fn add_root_authority(e: Env, authority: Address, credential_types: Vec<String>) {
+        let admin = validate_contract_admin(&e);
+        accreditation::add_root_authority(&e, &admin, &authority, &credential_types);
+    }
+
+    /// Accredit an issuer or accreditation body.
+    ///
+    /// Parameters:
+    /// - `accreditor`: active accreditation body (must sign).
+    /// - `subject`: address to accredit.
+    /// - `credential_types`: accredited types (must be within the accreditor's scope; empty = all).
+    /// - `can_accredit`: whether `subject` may accredit others.
+    fn accredit(
+        e: Env,
+        accreditor: Address,
+        subject: Address,
+        credential_types: Vec<String>,
+        can_accredit: bool,
+    ) {
+        accreditor.require_auth();
+        accreditation::accredit(&e, &accreditor, &subject, &credential_types, can_accredit);
+    }
+
+    /// Revoke an accreditation; cascades down the chain.
+    ///
+    /// Parameters:
+    /// - `revoker`: the subject's accreditor or the contract admin (must sign).
+    /// - `subject`: accredited address.
+    fn revoke_accreditation(e: Env, revoker: Address, subject: Address) {
+        revoker.require_auth();
+        accreditation::revoke(&e, &revoker, &subject);
+    }
+
+    /// Read an accreditation record (public read).
+    ///
+    /// Parameters:
+    /// - `subject`: accredited address.
+    fn get_accreditation(e: Env, subject: Address) -> Option<Accreditation> {
+        storage::read_accreditation(&e, &subject)
+    }
+
+    /// Check accreditation and return the chain of trust (public read).
+    ///
+    /// Parameters:
+    /// - `subject`: address to check.
+    /// - `credential_type`: optional credential type the accreditation must cover.
+    fn verify_accreditation(
+        e: Env,
+        subject: Address,
+        credential_type: Option<String>,
+    ) -> AccreditationStatus {
+        accreditation::status(&e, &subject, &credential_type)
+    }
+
+    // -----------------------------
+    // Credential schema registry
+    // -----------------------------
+
+    /// Publish a schema version (author-signed).
+    ///
+    /// Parameters:
+    /// - `author`: schema author (first publisher of `schema_id`).
+    /// - `schema_id`: schema identifier.
+    /// - `version`: version number, greater than the latest one.
+    /// - `hash`: SHA-256 of the JSON schema document.
+    fn publish_schema(e: Env, author: Address, schema_id: String, version: u32, hash: BytesN<32>) {
+        author.require_auth();
+        schema::publish(&e, &author, &schema_id, version, &hash);
+    }
+
+    /// Deprecate a schema version (author-signed).
+    ///
+    /// Parameters:
+    /// - `author`: schema author.
+    /// - `schema_id`: schema identifier.
+    /// - `version`: version to deprecate.
+    fn deprecate_schema(e: Env, author: Address, schema_id: String, version: u32) {
+        author.require_auth();
+        schema::deprecate(&e, &author, &schema_id, version);
+    }
+
+    /// Return a schema version (public read).
+    ///
+    /// Parameters:
+    /// - `schema_id`: schema identifier.
+    /// - `version`: version to read; `None` reads the latest.
+    fn get_schema(e: Env, schema_id: String, version: Option<u32>) -> Schema {
+        schema::get(&e, &schema_id, version)
+    }
+
     // -----------------------------
     // Migrations
     // -----------------------------
 
-    /// Migrate legacy storage layouts.
+    /// Migrate the next batch of a legacy storage layout.
     ///
     /// Parameters:
     /// - `owner`: `Some(owner)` migrates that owner's vault legacy VCs; `None` migrates legacy issuance registry.
-    fn migrate(e: Env, owner: Option<Address>) {
+    fn migrate(e: Env, owner: Option<Address>) -> MigrationProgress {
         match owner {
             Some(owner) => {
-                // Vault legacy migration is per-owner and requires vault admin.
-                validate_vault_admin(&e, &owner);
-
-                let vcs = storage::read_legacy_vault_vcs(&e, &owner);
-                if vcs.is_none() {
-                    panic_with_error!(e, ContractError::VCSAlreadyMigrated)
-                }
-
-                for vc in vcs.unwrap().iter() {
-                    verifiable_credential::store_vc(
-                        &e,
-                        &owner,
-                        vc.id.clone(),
-                        vc.data.clone(),
-                        vc.issuance_contract.clone(),
-                        vc.issuer_did.clone(),
-                    );
-                }
-
-                storage::remove_legacy_vault_vcs(&e, &owner);
+                // Vault legacy migration is per-owner and requires a single vault admin.
+                validate_vault_admin_direct(&e, &owner);
+                migration::migrate_vault_batch(&e, &owner)
             }
             None => {
                 // Issuance legacy migration is contract-admin only.
                 validate_contract_admin(&e);
-
-                let vcs = storage::read_legacy_issuance_vcs(&e);
-                if vcs.is_none() {
-                    panic_with_error!(e, ContractError::VCSAlreadyMigrated)
-                }
-
-                let revocations = storage::read_legacy_issuance_revocations(&e);
-
-                for vc_id in vcs.unwrap().iter() {
-                    match revocations.get(vc_id.clone()) {
-                        Some(revocation) => {
-                            storage::write_vc_status(&e, &vc_id.clone(), &VCStatus::Revoked(revocation.date))
-                        }
-                        None => storage::write_vc_status(&e, &vc_id, &VCStatus::Valid),
-                    }
-                }
-
-                storage::remove_legacy_issuance_vcs(&e);
-                storage::remove_legacy_issuance_revocations(&e);
+                migration::migrate_issuance_batch(&e)
             }
         }
     }
+
+    /// Read the progress of a legacy migration (public read).
+    ///
+    /// Parameters:
+    /// - `owner`: `Some(owner)` for that owner's vault migration; `None` for the issuance registry.
+    fn migration_progress(e: Env, owner: Option<Address>) -> MigrationProgress {
+        match owner {
+            Some(owner) => migration::vault_progress(&e, &owner),
+            None => migration::issuance_progress(&e),
+        }
+    }
 }
 
 // -----------------------------
@@ -421,6 +1599,14 @@ fn validate_vault_admin(e: &Env, owner: &Address) {
     admin.require_auth();
 }
 
+/// Vault admin signature for actions that require proposals while an admin set is configured.
+fn validate_vault_admin_direct(e: &Env, owner: &Address) {
+    validate_vault_admin(e, owner);
+    if vault_admins::has_admin_set(e, owner) {
+        panic_with_error!(e, ContractError::Unauthorized)
+    }
+}
+
 fn validate_vault_active(e: &Env, owner: &Address) {
     validate_vault_initialized(e, owner);
     let revoked = storage::read_vault_revoked(e, owner);
@@ -430,25 +1616,27 @@ fn validate_vault_active(e: &Env, owner: &Address) {
 }
 
 fn validate_issuer_signed_and_authorized(e: &Env, owner: &Address, issuer_addr: &Address) {
-    validate_vault_initialized(e, owner);
-
-    let issuers: Vec<Address> = storage::read_vault_issuers(e, owner);
-    if !issuer::is_authorized(&issuers, issuer_addr) {
-        panic_with_error!(e, ContractError::IssuerNotAuthorized)
-    }
-
+    validate_issuer_authorized_only(e, owner, issuer_addr);
     issuer_addr.require_auth();
 }
 
+/// Issuer must be in the vault's list (terms are only enforced when issuing).
 fn validate_issuer_authorized_only(e: &Env, owner: &Address, issuer_addr: &Address) {
     validate_vault_initialized(e, owner);
 
-    let issuers: Vec<Address> = storage::read_vault_issuers(e, owner);
+    let issuers = storage::read_vault_issuers(e, owner);
     if !issuer::is_authorized(&issuers, issuer_addr) {
         panic_with_error!(e, ContractError::IssuerNotAuthorized)
     }
 }
 
+/// Requires the controller's signature on an active DID document and returns it.
+fn validate_did_controller(e: &Env, did: &String) -> DidDocument {
+    let doc = did_registry::read_active(e, did);
+    doc.controller.require_auth();
+    doc
+}
+
 fn validate_vc_exists(e: &Env, vc_id: &String) {
     let status = storage::read_vc_status(e, vc_id);
     if status == VCStatus::Invalid {
@@ -456,6 +1644,37 @@ fn validate_vc_exists(e: &Env, vc_id: &String) {
     }
 }
 
+/// Accreditation must cover the VC's type; legacy VCs have none.
+fn credential_type_filter(vc: &verifiable_credential::VerifiableCredential) -> Option<String> {
+    if vc.credential_type.is_empty() {
+        None
+    } else {
+        Some(vc.credential_type.clone())
+    }
+}
+
+/// Comma-separated strkeys, issuer first and root authority last.
+fn chain_to_string(e: &Env, chain: &Vec<Address>) -> String {
+    const STRKEY_LEN: usize = 56;
+    let mut buf = [0u8; accreditation::MAX_CHAIN_DEPTH as usize * (STRKEY_LEN + 1)];
+    let mut len = 0;
+    for address in chain.iter() {
+        if len > 0 {
+            buf[len] = b',';
+            len += 1;
+        }
+        let strkey = address.to_string();
+        let key_len = strkey.len() as usize;
+        strkey.copy_into_slice(&mut buf[len..len + key_len]);
+        len += key_len;
+    }
+    String::from_bytes(e, &buf[..len])
+}
+
+fn bool_to_string(e: &Env, value: bool) -> String {
+    String::from_str(e, if value { "true" } else { "false" })
+}
+
 fn issuance_status_to_map(e: &Env, status: VCStatus) -> Map<String, String> {
     let status_k = String::from_str(e, "status");
     let since_k = String::from_str(e, "since");
@@ -493,32 +1712,11 @@ fn issuance_status_to_map(e: &Env, status: VCStatus) -> Map<String, String> {
 fn store_vc_payload(
     e: &Env,
     owner: &Address,
-    vc_id: String,
-    vc_data: String,
     issuer_addr: &Address,
-    issuer_did: String,
-    issuance_contract: Address,
+    vc: &verifiable_credential::VerifiableCredential,
 ) {
-    // Fee charging (if enabled): transfer from issuer -> fee_dest.
-    // Note: token contract itself will require auth from `issuer_addr` on transfer.
-    if storage::read_fee_enabled(e) {
-        let fee_token = storage::read_fee_token_contract(e);
-        let fee_dest = storage::read_fee_dest(e);
-        let fee_amount = storage::read_fee_amount(e);
-
-        e.invoke_contract::<()>(
-            &fee_token,
-            &symbol_short!("transfer"),
-            (issuer_addr.clone(), fee_dest, fee_amount).into_val(e),
-        );
-    }
+    // Fee charging (if enabled): transfer from issuer -> fee recipients, per the fee schedule.
+    fee::charge(e, issuer_addr, &vc.id, &vc.credential_type);
 
-    verifiable_credential::store_vc(
-        e,
-        owner,
-        vc_id,
-        vc_data,
-        issuance_contract,
-        issuer_did,
-    );
+    verifiable_credential::store_vc(e, owner, vc);
 }
@@ -0,0 +1,196 @@
+use crate::error::ContractError;
+use crate::{storage, validation};
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, String, Vec};
+
+/// Maximum number of verification methods per DID document.
+pub const MAX_VERIFICATION_METHODS: u32 = 10;
+
+/// Maximum number of service endpoints per DID document.
+pub const MAX_SERVICES: u32 = 10;
+
+/// Public key published in a DID document (ed25519).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationMethod {
+    /// Method ID, unique within the document (e.g. `key-1`).
+    pub id: String,
+
+    /// Raw ed25519 public key.
+    pub public_key: BytesN<32>,
+}
+
+/// Service endpoint published in a DID document.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Service {
+    /// Service ID, unique within the document.
+    pub id: String,
+
+    /// Service type (e.g. `LinkedDomains`).
+    pub service_type: String,
+
+    /// Service endpoint URI.
+    pub endpoint: String,
+}
+
+/// DID document managed on-chain by its controller.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DidDocument {
+    /// The DID this document describes.
+    pub id: String,
+
+    /// Address allowed to update the document.
+    pub controller: Address,
+
+    /// Published public keys.
+    pub verification_methods: Vec<VerificationMethod>,
+
+    /// Published service endpoints.
+    pub services: Vec<Service>,
+
+    /// Deactivated documents resolve but can no longer be updated.
+    pub deactivated: bool,
+
+    /// Ledger timestamp of the last update.
+    pub updated: u64,
+}
+
+pub fn register(
+    e: &Env,
+    did: &String,
+    controller: &Address,
+    verification_methods: &Vec<VerificationMethod>,
+    services: &Vec<Service>,
+) {
+    if storage::has_did_document(e, did) {
+        panic_with_error!(e, ContractError::DidAlreadyRegistered)
+    }
+    validate_control(e, did, controller);
+
+    let mut doc = DidDocument {
+        id: did.clone(),
+        controller: controller.clone(),
+        verification_methods: Vec::new(e),
+        services: Vec::new(e),
+        deactivated: false,
+        updated: 0,
+    };
+    for method in verification_methods.iter() {
+        add_verification_method(e, &mut doc, &method);
+    }
+    for service in services.iter() {
+        set_service(e, &mut doc, &service);
+    }
+
+    write(e, &mut doc);
+}
+
+/// The controller must prove it controls `did` before a document can be registered:
+///
+/// - `did:pkh:stellar:<network>:<controller>`: the controller's signature is the proof.
+/// - Any other DID (including a vault's self-declared `did_uri`): a contract admin
+///   attestation naming this controller (consumed on registration).
+fn validate_control(e: &Env, did: &String, controller: &Address) {
+    if validation::is_stellar_pkh_did(did, controller) {
+        return;
+    }
+    if storage::read_did_attestation(e, did).as_ref() == Some(controller) {
+        storage::remove_did_attestation(e, did);
+        return;
+    }
+    panic_with_error!(e, ContractError::DidControlNotProven)
+}
+
+/// Reads a DID document (deactivated or not).
+pub fn resolve(e: &Env, did: &String) -> DidDocument {
+    match storage::read_did_document(e, did) {
+        Some(doc) => doc,
+        None => panic_with_error!(e, ContractError::DidNotFound),
+    }
+}
+
+/// Reads a DID document that can still be updated.
+pub fn read_active(e: &Env, did: &String) -> DidDocument {
+    let doc = resolve(e, did);
+    if doc.deactivated {
+        panic_with_error!(e, ContractError::DidDeactivated)
+    }
+    doc
+}
+
+pub fn add_verification_method(e: &Env, doc: &mut DidDocument, method: &VerificationMethod) {
+    validation::validate_id(e, &method.id);
+    if find_method(doc, &method.id).is_some() {
+        panic_with_error!(e, ContractError::VerificationMethodAlreadyExists)
+    }
+    if doc.verification_methods.len() >= MAX_VERIFICATION_METHODS {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+    doc.verification_methods.push_back(method.clone());
+}
+
+pub fn remove_verification_method(e: &Env, doc: &mut DidDocument, method_id: &String) {
+    match find_method(doc, method_id) {
+        Some(idx) => doc.verification_methods.remove_unchecked(idx),
+        None => panic_with_error!(e, ContractError::VerificationMethodNotFound),
+    }
+}
+
+/// Replaces the public key of an existing verification method.
+pub fn rotate_key(e: &Env, doc: &mut DidDocument, method_id: &String, public_key: &BytesN<32>) {
+    match find_method(doc, method_id) {
+        Some(idx) => doc.verification_methods.set(
+            idx,
+            VerificationMethod {
+                id: method_id.clone(),
+                public_key: public_key.clone(),
+            },
+        ),
+        None => panic_with_error!(e, ContractError::VerificationMethodNotFound),
+    }
+}
+
+/// Adds a service, or replaces the one with the same ID.
+pub fn set_service(e: &Env, doc: &mut DidDocument, service: &Service) {
+    validation::validate_id(e, &service.id);
+    validation::validate_id(e, &service.service_type);
+    validation::validate_payload_len(e, service.endpoint.len());
+
+    match find_service(doc, &service.id) {
+        Some(idx) => doc.services.set(idx, service.clone()),
+        None => {
+            if doc.services.len() >= MAX_SERVICES {
+                panic_with_error!(e, ContractError::LimitExceeded)
+            }
+            doc.services.push_back(service.clone());
+        }
+    }
+}
+
+pub fn remove_service(e: &Env, doc: &mut DidDocument, service_id: &String) {
+    match find_service(doc, service_id) {
+        Some(idx) => doc.services.remove_unchecked(idx),
+        None => panic_with_error!(e, ContractError::ServiceNotFound),
+    }
+}
+
+/// Persists the document, stamping the update time.
+pub fn write(e: &Env, doc: &mut DidDocument) {
+    doc.updated = e.ledger().timestamp();
+    storage::write_did_document(e, &doc.id, doc);
+}
+
+fn find_method(doc: &DidDocument, method_id: &String) -> Option<u32> {
+    doc.verification_methods
+        .iter()
+        .position(|m| m.id == *method_id)
+        .map(|idx| idx as u32)
+}
+
+fn find_service(doc: &DidDocument, service_id: &String) -> Option<u32> {
+    doc.services
+        .iter()
+        .position(|s| s.id == *service_id)
+        .map(|idx| idx as u32)
+}
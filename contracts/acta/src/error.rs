@@ -38,4 +38,100 @@ pub enum ContractError {
 
     /// `vault_contract` parameter is not this contract (kept only for backwards-compat).
     InvalidVaultContract = 10,
+
+    /// Fee charging requires a complete fee config (`set_fee_config`).
+    FeeNotConfigured = 11,
+
+    /// The fee token transfer from the payer failed (e.g. insufficient balance).
+    FeeTransferFailed = 12,
+
+    /// The external issuance contract could not verify the VC.
+    IssuanceContractCallFailed = 13,
+
+    /// ID is empty or longer than the configured maximum.
+    InvalidId = 14,
+
+    /// Payload is empty or larger than the configured maximum.
+    PayloadTooLarge = 15,
+
+    /// DID is not of the form `did:<method>:<id>` or is too long.
+    InvalidDid = 16,
+
+    /// Fee amount is negative.
+    InvalidFeeAmount = 17,
+
+    /// Validation limits are out of range.
+    InvalidValidationLimits = 18,
+
+    /// A DID document is already registered for this DID.
+    DidAlreadyRegistered = 19,
+
+    /// No DID document is registered for this DID.
+    DidNotFound = 20,
+
+    /// The DID document is deactivated; updates are blocked.
+    DidDeactivated = 21,
+
+    /// The DID document has no verification method with this ID.
+    VerificationMethodNotFound = 22,
+
+    /// The DID document already has a verification method with this ID.
+    VerificationMethodAlreadyExists = 23,
+
+    /// The DID document has no service with this ID.
+    ServiceNotFound = 24,
+
+    /// A bounded list is full.
+    LimitExceeded = 25,
+
+    /// The issuer has not proven control of this DID.
+    IssuerDidNotBound = 26,
+
+    /// The caller lacks the role required for this action.
+    Unauthorized = 27,
+
+    /// The address is not an active accreditation body.
+    NotAccreditor = 28,
+
+    /// No active accreditation exists for this address.
+    AccreditationNotFound = 29,
+
+    /// Requested credential types exceed the accreditor's own scope.
+    AccreditationScopeExceeded = 30,
+
+    /// The issuer's authorization window has not started or has ended.
+    IssuerAuthorizationInactive = 31,
+
+    /// The issuer has used up its issuance allowance for this vault.
+    IssuanceLimitReached = 32,
+
+    /// An argument is out of range or inconsistent.
+    InvalidArgument = 33,
+
+    /// The issuer is not authorized for this credential type in this vault.
+    CredentialTypeNotAllowed = 34,
+
+    /// The pending request (or recovery, guardian config) does not exist.
+    NotFound = 35,
+
+    /// The pending request has expired.
+    Expired = 36,
+
+    /// The recovery lacks guardian approvals or its delay has not elapsed.
+    RecoveryNotReady = 37,
+
+    /// The issuer's prepaid credit does not cover the fee or withdrawal.
+    InsufficientCredit = 38,
+
+    /// The schema (or schema version) is not registered.
+    SchemaNotFound = 39,
+
+    /// The schema is deprecated and cannot be issued against.
+    SchemaDeprecated = 40,
+
+    /// The caller has not proven control of the DID.
+    DidControlNotProven = 41,
+
+    /// A VC with this ID has already been issued.
+    VCAlreadyExists = 42,
 }
@@ -0,0 +1,719 @@
+use crate::error::ContractError;
+use crate::{storage, validation};
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, Address, Env, IntoVal, String, Symbol, Vec,
+};
+
+/// Denominator of basis-point values.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Maximum number of volume tiers.
+pub const MAX_FEE_TIERS: u32 = 10;
+
+/// Maximum number of fee split recipients.
+pub const MAX_FEE_SHARES: u32 = 10;
+
+/// Longest refund grace window (7 days).
+pub const MAX_REFUND_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// Maximum number of escrowed payments settled by a single `settle_fees` call.
+pub const SETTLE_BATCH_SIZE: u32 = 25;
+
+/// Global issuance fee configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Whether fees are charged on issuance.
+    pub enabled: bool,
+
+    /// Soroban token contract used for charging.
+    pub token_contract: Address,
+
+    /// Address receiving the fees.
+    pub fee_dest: Address,
+
+    /// Amount charged per issuance.
+    pub fee_amount: i128,
+}
+
+/// Negotiated fee terms of one issuer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerFee {
+    /// Exempt issuers pay nothing.
+    pub exempt: bool,
+
+    /// Fixed amount per issuance, replacing type pricing and volume tiers.
+    pub amount: Option<i128>,
+
+    /// Token to charge instead of the global fee token.
+    pub token: Option<Address>,
+}
+
+/// Discount granted once an issuer has issued `min_issuances` credentials.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    /// Issuances (all vaults) from which the tier applies.
+    pub min_issuances: u32,
+
+    /// Discount in basis points (10000 = free).
+    pub discount_bps: u32,
+}
+
+/// Operations that can carry a fee.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeOperation {
+    Issue,
+    Push,
+    Share,
+    CreateVault,
+    VerificationReceipt,
+
+    /// Filing an issuer access request; pricing it deters request spam.
+    AccessRequest,
+}
+
+/// Share of every fee routed to one recipient.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeShare {
+    /// Recipient address.
+    pub recipient: Address,
+
+    /// Share in basis points; all shares sum to 10000.
+    pub bps: u32,
+}
+
+/// Agreement under which a sponsor pays the fees of a beneficiary issuer.
+///
+/// The sponsor funds it through a token allowance to this contract (`approve`), so no sponsor
+/// signature is needed at issuance. It applies only once the beneficiary accepted it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sponsorship {
+    /// Address paying the fees.
+    pub sponsor: Address,
+
+    /// Issuer whose fees are paid.
+    pub beneficiary: Address,
+
+    /// Fee token covered.
+    pub token: Address,
+
+    /// Maximum total amount the sponsor pays.
+    pub cap: i128,
+
+    /// Amount paid so far.
+    pub spent: i128,
+
+    /// Ledger timestamp after which the agreement no longer applies.
+    pub expires_at: u64,
+
+    /// Whether the beneficiary accepted the agreement.
+    pub accepted: bool,
+}
+
+/// Refund terms for issuances retracted shortly after issue.
+///
+/// While a policy is set, issuance fees are held in escrow until their window elapses.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPolicy {
+    /// Seconds after issuance during which the issuer may retract for a refund.
+    pub window: u64,
+
+    /// Refunds each issuer may receive in total.
+    pub max_refunds: u32,
+}
+
+/// Issuance fee held in escrow, refundable until `refundable_until`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeePayment {
+    /// Issuer charged.
+    pub issuer: Address,
+
+    /// Address that paid: the issuer, its sponsor, or this contract for prepaid credit.
+    pub payer: Address,
+
+    /// Fee token.
+    pub token: Address,
+
+    /// Amount held.
+    pub amount: i128,
+
+    /// Ledger timestamp until which the fee is refundable.
+    pub refundable_until: u64,
+}
+
+/// Fee an issuer would pay for its next issuance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeQuote {
+    /// Token charged; `None` when nothing is charged.
+    pub token: Option<Address>,
+
+    /// Amount charged.
+    pub amount: i128,
+}
+
+/// Returns the fee configuration, or `None` if it was never set.
+pub fn config(e: &Env) -> Option<FeeConfig> {
+    if !storage::has_fee_config(e) {
+        return None;
+    }
+
+    Some(FeeConfig {
+        enabled: storage::read_fee_enabled(e),
+        token_contract: storage::read_fee_token_contract(e),
+        fee_dest: storage::read_fee_dest(e),
+        fee_amount: storage::read_fee_amount(e),
+    })
+}
+
+/// Requires the contract admin or the fee manager.
+pub fn validate_fee_admin(e: &Env, caller: &Address) {
+    caller.require_auth();
+    let is_manager = storage::read_fee_manager(e).is_some_and(|manager| manager == *caller);
+    if !is_manager && *caller != storage::read_contract_admin(e) {
+        panic_with_error!(e, ContractError::Unauthorized)
+    }
+}
+
+pub fn set_issuer_fee(e: &Env, issuer: &Address, fee: &Option<IssuerFee>) {
+    match fee {
+        Some(fee) => {
+            if let Some(amount) = fee.amount {
+                validation::validate_fee_amount(e, amount);
+            }
+            storage::write_issuer_fee(e, issuer, fee);
+        }
+        None => storage::remove_issuer_fee(e, issuer),
+    }
+}
+
+pub fn set_operation_fee(e: &Env, operation: FeeOperation, amount: &Option<i128>) {
+    match amount {
+        Some(amount) => {
+            validation::validate_fee_amount(e, *amount);
+            storage::write_operation_fee(e, operation, amount);
+        }
+        None => storage::remove_operation_fee(e, operation),
+    }
+}
+
+pub fn set_credential_type_fee(e: &Env, credential_type: &String, amount: &Option<i128>) {
+    match amount {
+        Some(amount) => {
+            validation::validate_fee_amount(e, *amount);
+            storage::write_credential_type_fee(e, credential_type, amount);
+        }
+        None => storage::remove_credential_type_fee(e, credential_type),
+    }
+}
+
+/// Tiers must have strictly increasing thresholds and discounts of at most 100%.
+pub fn set_tiers(e: &Env, tiers: &Vec<FeeTier>) {
+    if tiers.len() > MAX_FEE_TIERS {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+    let mut previous: Option<u32> = None;
+    for tier in tiers.iter() {
+        if tier.discount_bps > BPS_DENOMINATOR || previous.is_some_and(|p| tier.min_issuances <= p)
+        {
+            panic_with_error!(e, ContractError::InvalidArgument)
+        }
+        previous = Some(tier.min_issuances);
+    }
+    storage::write_fee_tiers(e, tiers);
+}
+
+/// Sets the fee split; an empty list sends every fee to `FeeDest` again.
+///
+/// Shares must be non-zero, go to distinct recipients and sum to 10000 bps.
+pub fn set_shares(e: &Env, shares: &Vec<FeeShare>) {
+    if shares.len() > MAX_FEE_SHARES {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+    let mut total: u32 = 0;
+    for (i, share) in shares.iter().enumerate() {
+        let duplicate = shares
+            .iter()
+            .take(i)
+            .any(|other| other.recipient == share.recipient);
+        if share.bps == 0 || duplicate {
+            panic_with_error!(e, ContractError::InvalidArgument)
+        }
+        total = total.saturating_add(share.bps);
+    }
+    if !shares.is_empty() && total != BPS_DENOMINATOR {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    storage::write_fee_shares(e, shares);
+}
+
+/// Splits `amount` across the fee shares, or sends all of it to `FeeDest` without shares.
+///
+/// Each share is rounded down; the rounding remainder goes to the first recipient, so the
+/// parts always add up to `amount`.
+pub fn split(e: &Env, amount: i128) -> Vec<(Address, i128)> {
+    let shares = storage::read_fee_shares(e);
+    let mut parts = Vec::new(e);
+    if shares.is_empty() {
+        parts.push_back((storage::read_fee_dest(e), amount));
+        return parts;
+    }
+
+    let mut distributed = 0;
+    for share in shares.iter() {
+        let part = bps_of(e, amount, share.bps);
+        distributed += part;
+        parts.push_back((share.recipient, part));
+    }
+    let (first, part) = parts.get_unchecked(0);
+    parts.set(0, (first, part + amount - distributed));
+    parts
+}
+
+/// Computes the fee for `issuer`'s next issuance of `credential_type`.
+///
+/// Precedence: disabled fees or an exemption charge nothing; an issuer amount is charged as is;
+/// otherwise the type price (or the `Issue` operation price, or the global amount) is discounted
+/// by the issuer's volume tier.
+pub fn quote(e: &Env, issuer: &Address, credential_type: &String) -> FeeQuote {
+    let free = FeeQuote {
+        token: None,
+        amount: 0,
+    };
+    if !storage::read_fee_enabled(e) {
+        return free;
+    }
+
+    let issuer_fee = storage::read_issuer_fee(e, issuer);
+    if issuer_fee.as_ref().is_some_and(|fee| fee.exempt) {
+        return free;
+    }
+    let (token, amount) = match issuer_fee {
+        Some(fee) => (fee.token, fee.amount),
+        None => (None, None),
+    };
+
+    let amount = match amount {
+        Some(amount) => amount,
+        None => {
+            let base = storage::read_credential_type_fee(e, credential_type)
+                .or_else(|| storage::read_operation_fee(e, FeeOperation::Issue))
+                .unwrap_or_else(|| storage::read_fee_amount(e));
+            apply_discount(e, base, tier_discount(e, storage::read_issuer_issued(e, issuer)))
+        }
+    };
+
+    FeeQuote {
+        token: Some(token.unwrap_or_else(|| storage::read_fee_token_contract(e))),
+        amount,
+    }
+}
+
+/// Computes the fee of a non-issuance operation: its table price in the global fee token, or
+/// nothing if unpriced.
+pub fn quote_operation(e: &Env, operation: FeeOperation) -> FeeQuote {
+    match storage::read_operation_fee(e, operation) {
+        Some(amount) if storage::read_fee_enabled(e) => FeeQuote {
+            token: Some(storage::read_fee_token_contract(e)),
+            amount,
+        },
+        _ => FeeQuote {
+            token: None,
+            amount: 0,
+        },
+    }
+}
+
+/// Charges `issuer` for issuing `vc_id` and counts it towards its volume tier.
+///
+/// Under a refund policy the fee is held in escrow instead of being paid out.
+pub fn charge(e: &Env, issuer: &Address, vc_id: &String, credential_type: &String) {
+    let quote = quote(e, issuer, credential_type);
+    match storage::read_refund_policy(e) {
+        Some(policy) => escrow(e, issuer, vc_id, quote, policy.window),
+        None => collect(e, FeeOperation::Issue, issuer, quote),
+    }
+
+    let issued = storage::read_issuer_issued(e, issuer);
+    storage::write_issuer_issued(e, issuer, &issued.saturating_add(1));
+}
+
+/// Charges `account` for a non-issuance operation.
+pub fn charge_operation(e: &Env, operation: FeeOperation, account: &Address) {
+    collect(e, operation, account, quote_operation(e, operation));
+}
+
+/// Offers a sponsorship to the beneficiary, replacing its current one once accepted.
+///
+/// An accepted agreement of another sponsor can only be replaced once expired or used up.
+pub fn sponsor(
+    e: &Env,
+    sponsor: &Address,
+    beneficiary: &Address,
+    token: &Address,
+    cap: i128,
+    expires_at: u64,
+) {
+    if cap <= 0 || expires_at <= e.ledger().timestamp() {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    if let Some(current) = storage::read_sponsorship(e, beneficiary) {
+        if current.sponsor != *sponsor && remaining(e, &current) > 0 {
+            panic_with_error!(e, ContractError::Unauthorized)
+        }
+    }
+
+    let sponsorship = Sponsorship {
+        sponsor: sponsor.clone(),
+        beneficiary: beneficiary.clone(),
+        token: token.clone(),
+        cap,
+        spent: 0,
+        expires_at,
+        accepted: false,
+    };
+    storage::write_sponsorship(e, beneficiary, &sponsorship);
+}
+
+/// Beneficiary's consent to the pending offer of `sponsor`.
+pub fn accept_sponsorship(e: &Env, beneficiary: &Address, sponsor: &Address) {
+    match storage::read_sponsorship(e, beneficiary) {
+        Some(mut current) if current.sponsor == *sponsor && !current.accepted => {
+            current.accepted = true;
+            storage::write_sponsorship(e, beneficiary, &current);
+        }
+        _ => panic_with_error!(e, ContractError::NotFound),
+    }
+}
+
+/// Ends a sponsorship on behalf of its sponsor or its beneficiary.
+pub fn end_sponsorship(e: &Env, caller: &Address, beneficiary: &Address) {
+    match storage::read_sponsorship(e, beneficiary) {
+        Some(current) if current.sponsor == *caller || current.beneficiary == *caller => {
+            storage::remove_sponsorship(e, beneficiary)
+        }
+        Some(_) => panic_with_error!(e, ContractError::Unauthorized),
+        None => panic_with_error!(e, ContractError::NotFound),
+    }
+}
+
+/// Budget left under a sponsorship; zero until accepted and once expired.
+pub fn remaining(e: &Env, sponsorship: &Sponsorship) -> i128 {
+    if !sponsorship.accepted || sponsorship.expires_at < e.ledger().timestamp() {
+        return 0;
+    }
+    sponsorship.cap - sponsorship.spent
+}
+
+/// Moves `amount` of `token` from the issuer into escrow, opening or topping up its credit.
+pub fn deposit_credit(e: &Env, issuer: &Address, token: &Address, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    transfer(e, token, issuer, &e.current_contract_address(), amount);
+
+    let balance = storage::read_fee_credit(e, issuer, token).unwrap_or(0);
+    storage::write_fee_credit(e, issuer, token, &(balance + amount));
+}
+
+/// Returns unused credit to the issuer; the credit account stays open, even at zero.
+pub fn withdraw_credit(e: &Env, issuer: &Address, token: &Address, amount: i128) {
+    if amount <= 0 {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    let balance = storage::read_fee_credit(e, issuer, token).unwrap_or(0);
+    if amount > balance {
+        panic_with_error!(e, ContractError::InsufficientCredit)
+    }
+
+    storage::write_fee_credit(e, issuer, token, &(balance - amount));
+    transfer(e, token, &e.current_contract_address(), issuer, amount);
+}
+
+/// Refunds the remaining credit and closes the account, so fees are paid directly again.
+///
+/// Returns the amount refunded.
+pub fn close_credit(e: &Env, issuer: &Address, token: &Address) -> i128 {
+    let balance = match storage::read_fee_credit(e, issuer, token) {
+        Some(balance) => balance,
+        None => panic_with_error!(e, ContractError::NotFound),
+    };
+
+    storage::remove_fee_credit(e, issuer, token);
+    if balance > 0 {
+        transfer(e, token, &e.current_contract_address(), issuer, balance);
+    }
+    balance
+}
+
+pub fn set_refund_policy(e: &Env, policy: &Option<RefundPolicy>) {
+    match policy {
+        Some(policy) => {
+            if policy.window == 0 || policy.window > MAX_REFUND_WINDOW {
+                panic_with_error!(e, ContractError::InvalidArgument)
+            }
+            storage::write_refund_policy(e, policy);
+        }
+        None => storage::remove_refund_policy(e),
+    }
+}
+
+/// Refunds the escrowed fee of `vc_id` to whoever paid it.
+///
+/// Guards: only the charged issuer, once, within the window fixed at issuance and up to the
+/// policy's refund allowance. The issuance no longer counts towards volume tiers.
+pub fn refund(e: &Env, issuer: &Address, vc_id: &String) {
+    let payment = match storage::read_fee_payment(e, vc_id) {
+        Some(payment) => payment,
+        None => panic_with_error!(e, ContractError::NotFound),
+    };
+    if payment.issuer != *issuer {
+        panic_with_error!(e, ContractError::Unauthorized)
+    }
+    if payment.refundable_until < e.ledger().timestamp() {
+        panic_with_error!(e, ContractError::Expired)
+    }
+    let refunds = storage::read_issuer_refunds(e, issuer);
+    let max_refunds = storage::read_refund_policy(e).map_or(0, |policy| policy.max_refunds);
+    if refunds >= max_refunds {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+
+    storage::remove_fee_payment(e, vc_id);
+    storage::write_issuer_refunds(e, issuer, &(refunds + 1));
+    let issued = storage::read_issuer_issued(e, issuer);
+    storage::write_issuer_issued(e, issuer, &issued.saturating_sub(1));
+
+    let this = e.current_contract_address();
+    if payment.payer == this {
+        let balance = storage::read_fee_credit(e, issuer, &payment.token).unwrap_or(0);
+        storage::write_fee_credit(e, issuer, &payment.token, &(balance + payment.amount));
+    } else {
+        transfer(e, &payment.token, &this, &payment.payer, payment.amount);
+        if payment.payer != *issuer {
+            restore_sponsorship(e, issuer, &payment.payer, payment.amount);
+        }
+    }
+
+    let charged = storage::read_fees_charged(e, issuer, &payment.token);
+    storage::write_fees_charged(e, issuer, &payment.token, &(charged - payment.amount));
+    e.events().publish(
+        (symbol_short!("fee_rfd"), issuer.clone()),
+        (vc_id.clone(), payment.token, payment.amount, payment.payer),
+    );
+}
+
+/// Pays out the escrowed fees whose window has elapsed; others are skipped.
+///
+/// Returns the number of payments settled.
+pub fn settle(e: &Env, vc_ids: &Vec<String>) -> u32 {
+    if vc_ids.len() > SETTLE_BATCH_SIZE {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+
+    let now = e.ledger().timestamp();
+    let this = e.current_contract_address();
+    let mut settled = 0;
+    for vc_id in vc_ids.iter() {
+        let payment = match storage::read_fee_payment(e, &vc_id) {
+            Some(payment) if payment.refundable_until < now => payment,
+            _ => continue,
+        };
+        storage::remove_fee_payment(e, &vc_id);
+        for (recipient, part) in split(e, payment.amount).iter() {
+            if part > 0 {
+                transfer(e, &payment.token, &this, &recipient, part);
+                record_collected(e, &recipient, &payment.token, part);
+            }
+        }
+        settled += 1;
+    }
+    settled
+}
+
+/// Source of a fee payment.
+enum Payer {
+    Sponsor(Address),
+    Credit,
+    Account,
+}
+
+/// Transfers a quoted fee (split across recipients), records it in the fee ledger and emits a
+/// `fee` event.
+fn collect(e: &Env, operation: FeeOperation, account: &Address, quote: FeeQuote) {
+    let token = match quote.token {
+        Some(token) if quote.amount > 0 => token,
+        _ => return,
+    };
+
+    let payer = draw_payer(e, account, &token, quote.amount);
+    for (recipient, part) in split(e, quote.amount).iter() {
+        if part > 0 {
+            pay(e, &payer, account, &token, &recipient, part);
+            record_collected(e, &recipient, &token, part);
+        }
+    }
+    record_charge(e, operation, account, &token, quote.amount, &payer);
+}
+
+/// Moves a quoted issuance fee into escrow and records the payment against `vc_id`.
+fn escrow(e: &Env, issuer: &Address, vc_id: &String, quote: FeeQuote, window: u64) {
+    let token = match quote.token {
+        Some(token) if quote.amount > 0 => token,
+        _ => return,
+    };
+
+    let this = e.current_contract_address();
+    let payer = draw_payer(e, issuer, &token, quote.amount);
+    pay(e, &payer, issuer, &token, &this, quote.amount);
+
+    let payment = FeePayment {
+        issuer: issuer.clone(),
+        payer: payer_address(e, &payer, issuer),
+        token: token.clone(),
+        amount: quote.amount,
+        refundable_until: e.ledger().timestamp().saturating_add(window),
+    };
+    storage::write_fee_payment(e, vc_id, &payment);
+    record_charge(e, FeeOperation::Issue, issuer, &token, quote.amount, &payer);
+}
+
+/// Payer precedence:
+/// - an active sponsorship covering the whole fee, drawn into this contract up front (a failed
+///   transfer, e.g. an exhausted allowance, falls through);
+/// - the account's prepaid credit in the fee token while its credit account is open (no token
+///   auth of its own; an insufficient balance, zero included, is an error, not a split payment);
+/// - the account itself (the token contract requires auth from `account` on transfer).
+fn draw_payer(e: &Env, account: &Address, token: &Address, amount: i128) -> Payer {
+    if let Some(sponsor) = draw_sponsorship(e, account, token, amount) {
+        return Payer::Sponsor(sponsor);
+    }
+    match storage::read_fee_credit(e, account, token) {
+        Some(balance) => {
+            draw_credit(e, account, token, balance, amount);
+            Payer::Credit
+        }
+        None => Payer::Account,
+    }
+}
+
+fn pay(e: &Env, payer: &Payer, account: &Address, token: &Address, to: &Address, amount: i128) {
+    let this = e.current_contract_address();
+    match payer {
+        Payer::Sponsor(_) | Payer::Credit if *to == this => {}
+        Payer::Sponsor(_) | Payer::Credit => transfer(e, token, &this, to, amount),
+        Payer::Account => transfer(e, token, account, to, amount),
+    }
+}
+
+fn payer_address(e: &Env, payer: &Payer, account: &Address) -> Address {
+    match payer {
+        Payer::Sponsor(sponsor) => sponsor.clone(),
+        Payer::Credit => e.current_contract_address(),
+        Payer::Account => account.clone(),
+    }
+}
+
+fn record_charge(
+    e: &Env,
+    operation: FeeOperation,
+    account: &Address,
+    token: &Address,
+    amount: i128,
+    payer: &Payer,
+) {
+    let charged = storage::read_fees_charged(e, account, token);
+    storage::write_fees_charged(e, account, token, &(charged + amount));
+    e.events().publish(
+        (symbol_short!("fee"), account.clone()),
+        (operation, token.clone(), amount, payer_address(e, payer, account)),
+    );
+}
+
+fn record_collected(e: &Env, recipient: &Address, token: &Address, amount: i128) {
+    let collected = storage::read_fees_collected(e, recipient, token);
+    storage::write_fees_collected(e, recipient, token, &(collected + amount));
+}
+
+/// Gives a refunded amount back to the sponsorship it was drawn from, if still in place.
+fn restore_sponsorship(e: &Env, beneficiary: &Address, sponsor: &Address, amount: i128) {
+    if let Some(mut sponsorship) = storage::read_sponsorship(e, beneficiary) {
+        if sponsorship.sponsor == *sponsor {
+            sponsorship.spent = (sponsorship.spent - amount).max(0);
+            storage::write_sponsorship(e, beneficiary, &sponsorship);
+        }
+    }
+}
+
+/// Draws `amount` from the sponsor into this contract if an agreement covers it, counting it as
+/// spent; `None` if there is no such agreement or the sponsor's transfer fails.
+fn draw_sponsorship(e: &Env, issuer: &Address, token: &Address, amount: i128) -> Option<Address> {
+    let mut sponsorship = storage::read_sponsorship(e, issuer)?;
+    if sponsorship.token != *token || remaining(e, &sponsorship) < amount {
+        return None;
+    }
+    let this = e.current_contract_address();
+    if !try_transfer_from(e, token, &sponsorship.sponsor, &this, amount) {
+        return None;
+    }
+    sponsorship.spent += amount;
+    storage::write_sponsorship(e, issuer, &sponsorship);
+    Some(sponsorship.sponsor)
+}
+
+fn draw_credit(e: &Env, issuer: &Address, token: &Address, balance: i128, amount: i128) {
+    if balance < amount {
+        panic_with_error!(e, ContractError::InsufficientCredit)
+    }
+    storage::write_fee_credit(e, issuer, token, &(balance - amount));
+}
+
+fn transfer(e: &Env, token: &Address, from: &Address, to: &Address, amount: i128) {
+    let transfer = e.try_invoke_contract::<(), soroban_sdk::Error>(
+        token,
+        &symbol_short!("transfer"),
+        (from.clone(), to.clone(), amount).into_val(e),
+    );
+    if !matches!(transfer, Ok(Ok(()))) {
+        panic_with_error!(e, ContractError::FeeTransferFailed)
+    }
+}
+
+/// Spends `from`'s allowance to this contract; `false` if the token rejects the transfer.
+fn try_transfer_from(e: &Env, token: &Address, from: &Address, to: &Address, amount: i128) -> bool {
+    let transfer = e.try_invoke_contract::<(), soroban_sdk::Error>(
+        token,
+        &Symbol::new(e, "transfer_from"),
+        (e.current_contract_address(), from.clone(), to.clone(), amount).into_val(e),
+    );
+    matches!(transfer, Ok(Ok(())))
+}
+
+fn tier_discount(e: &Env, issued: u32) -> u32 {
+    let mut discount = 0;
+    for tier in storage::read_fee_tiers(e).iter() {
+        if issued >= tier.min_issuances {
+            discount = tier.discount_bps;
+        }
+    }
+    discount
+}
+
+fn apply_discount(e: &Env, amount: i128, discount_bps: u32) -> i128 {
+    bps_of(e, amount, BPS_DENOMINATOR - discount_bps)
+}
+
+/// `bps` basis points of `amount`; amounts too large to scale are rejected, not wrapped.
+fn bps_of(e: &Env, amount: i128, bps: u32) -> i128 {
+    match amount.checked_mul(i128::from(bps)) {
+        Some(scaled) => scaled / i128::from(BPS_DENOMINATOR),
+        None => panic_with_error!(e, ContractError::InvalidFeeAmount),
+    }
+}
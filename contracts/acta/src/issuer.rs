@@ -1,34 +1,266 @@
 use crate::error::ContractError;
-use crate::storage;
-use soroban_sdk::{panic_with_error, Address, Env, Vec};
+use crate::{storage, validation};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, String, Vec};
+
+/// Maximum number of authorized issuers per vault.
+pub const MAX_VAULT_ISSUERS: u32 = 100;
+
+/// Limits attached to an issuer authorization in one vault.
+///
+/// Issuers authorized through `authorize_issuers` / `update_issuers` (or before limits existed)
+/// have no stored terms and behave as `unlimited()`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerTerms {
+    /// Ledger timestamp from which the issuer may issue (inclusive).
+    pub valid_from: Option<u64>,
+
+    /// Ledger timestamp until which the issuer may issue (inclusive).
+    pub valid_until: Option<u64>,
+
+    /// Maximum number of issuances into the vault; `None` means unlimited.
+    pub max_issuances: Option<u32>,
+
+    /// Credential types the issuer may issue; empty means any type.
+    pub credential_types: Vec<String>,
+
+    /// Issuances recorded so far.
+    pub issued: u32,
+}
+
+/// Result of a bulk issuer update: entries that actually changed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerUpdate {
+    /// Issuers newly authorized (already authorized ones are skipped).
+    pub added: Vec<Address>,
+
+    /// Issuers removed (unknown ones are skipped).
+    pub removed: Vec<Address>,
+}
+
+/// Remaining allowance of an issuer in a vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerAllowance {
+    /// Whether the issuer can issue right now.
+    pub active: bool,
+
+    /// Start of the authorization window, if any.
+    pub valid_from: Option<u64>,
+
+    /// End of the authorization window, if any.
+    pub valid_until: Option<u64>,
+
+    /// Issuances recorded so far (only tracked for issuers authorized with terms).
+    pub issued: u32,
+
+    /// Issuances left; `None` means unlimited.
+    pub remaining: Option<u32>,
+
+    /// Credential types the issuer may issue; empty means any type.
+    pub credential_types: Vec<String>,
+}
+
+pub fn unlimited(e: &Env) -> IssuerTerms {
+    IssuerTerms {
+        valid_from: None,
+        valid_until: None,
+        max_issuances: None,
+        credential_types: Vec::new(e),
+        issued: 0,
+    }
+}
 
 pub fn authorize_issuer(e: &Env, owner: &Address, issuer: &Address) {
-    let mut issuers: Vec<Address> = storage::read_vault_issuers(e, owner);
+    authorize_issuer_with_terms(e, owner, issuer, &unlimited(e));
+}
+
+pub fn authorize_issuer_with_terms(
+    e: &Env,
+    owner: &Address,
+    issuer: &Address,
+    terms: &IssuerTerms,
+) {
+    let mut issuers = storage::read_vault_issuers(e, owner);
 
     if is_authorized(&issuers, issuer) {
         panic_with_error!(e, ContractError::IssuerAlreadyAuthorized)
     }
-    issuers.push_front(issuer.clone());
+    validate_terms(e, terms);
+    issuers.set(issuer.clone(), true);
+    validate_size(e, &issuers);
 
     storage::write_vault_issuers(e, owner, &issuers);
+    if *terms == unlimited(e) {
+        storage::remove_issuer_terms(e, owner, issuer);
+    } else {
+        storage::write_issuer_terms(e, owner, issuer, terms);
+    }
 }
 
+/// Merges `issuers` into the vault's issuer set (duplicates are ignored).
 pub fn authorize_issuers(e: &Env, owner: &Address, issuers: &Vec<Address>) {
-    storage::write_vault_issuers(e, owner, issuers);
+    update_issuers(e, owner, issuers, &Vec::new(e));
+}
+
+/// Adds and removes issuers in one call and reports what changed.
+///
+/// Added issuers get unlimited terms; removed issuers lose theirs.
+pub fn update_issuers(
+    e: &Env,
+    owner: &Address,
+    add: &Vec<Address>,
+    remove: &Vec<Address>,
+) -> IssuerUpdate {
+    let mut issuers = storage::read_vault_issuers(e, owner);
+    let mut update = IssuerUpdate {
+        added: Vec::new(e),
+        removed: Vec::new(e),
+    };
+
+    for issuer in remove.iter() {
+        if add.contains(issuer.clone()) {
+            panic_with_error!(e, ContractError::InvalidArgument)
+        }
+        if issuers.contains_key(issuer.clone()) {
+            issuers.remove(issuer.clone());
+            storage::remove_issuer_terms(e, owner, &issuer);
+            update.removed.push_back(issuer);
+        }
+    }
+    for issuer in add.iter() {
+        if !issuers.contains_key(issuer.clone()) {
+            issuers.set(issuer.clone(), true);
+            // Drop terms left over from an earlier authorization.
+            storage::remove_issuer_terms(e, owner, &issuer);
+            update.added.push_back(issuer);
+        }
+    }
+    validate_size(e, &issuers);
+
+    storage::write_vault_issuers(e, owner, &issuers);
+    update
 }
 
 pub fn revoke_issuer(e: &Env, owner: &Address, issuer: &Address) {
     let mut issuers = storage::read_vault_issuers(e, owner);
 
-    if let Some(issuer_index) = issuers.first_index_of(issuer) {
-        issuers.remove(issuer_index);
-    } else {
+    if !is_authorized(&issuers, issuer) {
         panic_with_error!(e, ContractError::IssuerNotAuthorized)
     }
+    issuers.remove(issuer.clone());
 
     storage::write_vault_issuers(e, owner, &issuers);
+    storage::remove_issuer_terms(e, owner, issuer);
+}
+
+pub fn is_authorized(issuers: &Map<Address, bool>, issuer: &Address) -> bool {
+    issuers.contains_key(issuer.clone())
+}
+
+fn validate_size(e: &Env, issuers: &Map<Address, bool>) {
+    if issuers.len() > MAX_VAULT_ISSUERS {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+}
+
+/// Rejects an empty window or a zero issuance cap.
+pub fn validate_terms(e: &Env, terms: &IssuerTerms) {
+    if let (Some(from), Some(until)) = (terms.valid_from, terms.valid_until) {
+        if from > until {
+            panic_with_error!(e, ContractError::InvalidArgument)
+        }
+    }
+    if terms.max_issuances == Some(0) {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    validate_credential_types(e, &terms.credential_types);
+}
+
+pub fn validate_credential_types(e: &Env, credential_types: &Vec<String>) {
+    for credential_type in credential_types.iter() {
+        validation::validate_id(e, &credential_type);
+    }
+}
+
+/// Enforces the authorization window and issuance cap of an authorized issuer.
+pub fn validate_within_terms(e: &Env, owner: &Address, issuer: &Address) {
+    let terms = read_terms(e, owner, issuer);
+
+    if !in_window(e, &terms) {
+        panic_with_error!(e, ContractError::IssuerAuthorizationInactive)
+    }
+    if remaining(&terms) == Some(0) {
+        panic_with_error!(e, ContractError::IssuanceLimitReached)
+    }
+}
+
+/// Restricts an authorized issuer to `credential_types` (empty means any type).
+pub fn set_credential_types(
+    e: &Env,
+    owner: &Address,
+    issuer: &Address,
+    credential_types: &Vec<String>,
+) {
+    if !is_authorized(&storage::read_vault_issuers(e, owner), issuer) {
+        panic_with_error!(e, ContractError::IssuerNotAuthorized)
+    }
+    validate_credential_types(e, credential_types);
+    let mut terms = read_terms(e, owner, issuer);
+    terms.credential_types = credential_types.clone();
+    storage::write_issuer_terms(e, owner, issuer, &terms);
+}
+
+/// Rejects a credential type outside the issuer's scope in this vault.
+pub fn validate_credential_type(
+    e: &Env,
+    owner: &Address,
+    issuer: &Address,
+    credential_type: &String,
+) {
+    let terms = read_terms(e, owner, issuer);
+    if !terms.credential_types.is_empty() && !terms.credential_types.contains(credential_type) {
+        panic_with_error!(e, ContractError::CredentialTypeNotAllowed)
+    }
+}
+
+/// Counts one issuance against the issuer's allowance.
+///
+/// Issuers authorized without terms are not tracked, so they never get a terms entry.
+pub fn record_issuance(e: &Env, owner: &Address, issuer: &Address) {
+    if let Some(mut terms) = storage::read_issuer_terms(e, owner, issuer) {
+        terms.issued += 1;
+        storage::write_issuer_terms(e, owner, issuer, &terms);
+    }
+}
+
+pub fn allowance(e: &Env, owner: &Address, issuer: &Address) -> IssuerAllowance {
+    let terms = read_terms(e, owner, issuer);
+    let remaining = remaining(&terms);
+
+    IssuerAllowance {
+        active: in_window(e, &terms) && remaining != Some(0),
+        valid_from: terms.valid_from,
+        valid_until: terms.valid_until,
+        issued: terms.issued,
+        remaining,
+        credential_types: terms.credential_types,
+    }
+}
+
+fn read_terms(e: &Env, owner: &Address, issuer: &Address) -> IssuerTerms {
+    storage::read_issuer_terms(e, owner, issuer).unwrap_or_else(|| unlimited(e))
+}
+
+fn in_window(e: &Env, terms: &IssuerTerms) -> bool {
+    let now = e.ledger().timestamp();
+    terms.valid_from.is_none_or(|from| now >= from)
+        && terms.valid_until.is_none_or(|until| now <= until)
 }
 
-pub fn is_authorized(issuers: &Vec<Address>, issuer: &Address) -> bool {
-    issuers.contains(issuer.clone())
+fn remaining(terms: &IssuerTerms) -> Option<u32> {
+    terms
+        .max_issuances
+        .map(|max| max.saturating_sub(terms.issued))
 }
@@ -0,0 +1,74 @@
+use crate::error::ContractError;
+use crate::{storage, validation};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+
+/// How an issuer proved control of a DID.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BindingProof {
+    /// `did:pkh:stellar` DID of the issuer address itself, proven by the issuer's signature.
+    Account,
+
+    /// Attested by the given address (contract admin or accreditation body).
+    Attestation(Address),
+}
+
+/// Verified link between an issuer address and a DID it controls.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerDidBinding {
+    /// Issuer address.
+    pub issuer: Address,
+
+    /// DID the issuer controls.
+    pub did: String,
+
+    /// Proof accepted when binding.
+    pub proof: BindingProof,
+
+    /// Ledger timestamp of the binding.
+    pub bound_at: u64,
+}
+
+/// Binds `did` to `issuer` on the issuer's own signature (checked by caller).
+///
+/// Only `did:pkh:stellar:<network>:<issuer>` can be proven this way; any other DID needs an
+/// attestation, since a self-registered document proves nothing about who controls it.
+pub fn bind_own(e: &Env, issuer: &Address, did: &String) {
+    if !validation::is_stellar_pkh_did(did, issuer) {
+        panic_with_error!(e, ContractError::DidControlNotProven)
+    }
+    write_binding(e, issuer, did, BindingProof::Account);
+}
+
+/// Binds `did` to `issuer` on the attester's word (attester authority checked by caller).
+pub fn bind_attested(e: &Env, issuer: &Address, did: &String, attester: &Address) {
+    write_binding(e, issuer, did, BindingProof::Attestation(attester.clone()));
+}
+
+pub fn unbind(e: &Env, issuer: &Address, did: &String) {
+    if storage::read_issuer_did_binding(e, issuer, did).is_none() {
+        panic_with_error!(e, ContractError::IssuerDidNotBound)
+    }
+    storage::remove_issuer_did_binding(e, issuer, did);
+}
+
+pub fn is_bound(e: &Env, issuer: &Address, did: &String) -> bool {
+    storage::read_issuer_did_binding(e, issuer, did).is_some()
+}
+
+pub fn validate_bound(e: &Env, issuer: &Address, did: &String) {
+    if !is_bound(e, issuer, did) {
+        panic_with_error!(e, ContractError::IssuerDidNotBound)
+    }
+}
+
+fn write_binding(e: &Env, issuer: &Address, did: &String, proof: BindingProof) {
+    let binding = IssuerDidBinding {
+        issuer: issuer.clone(),
+        did: did.clone(),
+        proof,
+        bound_at: e.ledger().timestamp(),
+    };
+    storage::write_issuer_did_binding(e, issuer, did, &binding);
+}
@@ -0,0 +1,140 @@
+use crate::error::ContractError;
+use crate::issuer::{self, IssuerTerms};
+use crate::storage;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, String, Vec};
+
+/// Maximum number of pending access requests per vault.
+pub const MAX_PENDING_REQUESTS: u32 = 20;
+
+/// Furthest a request may expire after it is filed (90 days), so stale requests free their
+/// slot.
+pub const MAX_REQUEST_HORIZON: u64 = 90 * 24 * 60 * 60;
+
+/// Access request filed on-chain by an issuer, pending vault admin approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerAccessRequest {
+    /// Requesting issuer.
+    pub issuer: Address,
+
+    /// Credential types the issuer asks to issue; empty means any type.
+    pub credential_types: Vec<String>,
+
+    /// Ledger timestamp when the request was filed.
+    pub requested_at: u64,
+
+    /// Ledger timestamp after which the request lapses if not approved.
+    pub expires_at: u64,
+
+    /// Seconds the authorization lasts from approval; `None` for no end.
+    pub access_period: Option<u64>,
+}
+
+/// Files (or replaces) the issuer's pending request for `owner`'s vault.
+pub fn file(
+    e: &Env,
+    owner: &Address,
+    issuer: &Address,
+    credential_types: &Vec<String>,
+    expires_at: u64,
+    access_period: Option<u64>,
+) {
+    let now = e.ledger().timestamp();
+    if expires_at <= now || expires_at - now > MAX_REQUEST_HORIZON || access_period == Some(0) {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    if issuer::is_authorized(&storage::read_vault_issuers(e, owner), issuer) {
+        panic_with_error!(e, ContractError::IssuerAlreadyAuthorized)
+    }
+    issuer::validate_credential_types(e, credential_types);
+
+    let mut requests = read_pending(e, owner);
+    if !requests.contains_key(issuer.clone()) && requests.len() >= MAX_PENDING_REQUESTS {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+
+    requests.set(
+        issuer.clone(),
+        IssuerAccessRequest {
+            issuer: issuer.clone(),
+            credential_types: credential_types.clone(),
+            requested_at: now,
+            expires_at,
+            access_period,
+        },
+    );
+    storage::write_issuer_requests(e, owner, &requests);
+}
+
+/// Lists pending, unexpired requests.
+pub fn list(e: &Env, owner: &Address) -> Vec<IssuerAccessRequest> {
+    read_pending(e, owner).values()
+}
+
+/// Authorizes the issuer with the requested scope for the requested period, counted from
+/// approval, and clears the request.
+pub fn approve(e: &Env, owner: &Address, issuer: &Address) {
+    let request = take(e, owner, issuer);
+
+    let now = e.ledger().timestamp();
+    let terms = IssuerTerms {
+        credential_types: request.credential_types,
+        valid_until: request.access_period.map(|period| now.saturating_add(period)),
+        ..issuer::unlimited(e)
+    };
+    issuer::authorize_issuer_with_terms(e, owner, issuer, &terms);
+}
+
+/// Clears the request without authorizing the issuer.
+pub fn deny(e: &Env, owner: &Address, issuer: &Address) {
+    take(e, owner, issuer);
+}
+
+/// Clears the listed issuers' requests (unknown or expired ones are skipped).
+///
+/// Returns the number of requests removed.
+pub fn deny_many(e: &Env, owner: &Address, issuers: &Vec<Address>) -> u32 {
+    let mut requests = storage::read_issuer_requests(e, owner);
+    let before = requests.len();
+    for issuer in issuers.iter() {
+        requests.remove(issuer);
+    }
+    storage::write_issuer_requests(e, owner, &requests);
+    before - requests.len()
+}
+
+/// Clears every request of the vault, expired or not.
+///
+/// Returns the number of requests removed.
+pub fn purge(e: &Env, owner: &Address) -> u32 {
+    let removed = storage::read_issuer_requests(e, owner).len();
+    storage::write_issuer_requests(e, owner, &Map::new(e));
+    removed
+}
+
+fn take(e: &Env, owner: &Address, issuer: &Address) -> IssuerAccessRequest {
+    let mut requests = storage::read_issuer_requests(e, owner);
+    let request = match requests.get(issuer.clone()) {
+        Some(request) => request,
+        None => panic_with_error!(e, ContractError::NotFound),
+    };
+    if request.expires_at < e.ledger().timestamp() {
+        panic_with_error!(e, ContractError::Expired)
+    }
+
+    requests.remove(issuer.clone());
+    storage::write_issuer_requests(e, owner, &requests);
+    request
+}
+
+/// Reads the vault's requests, dropping the expired ones.
+fn read_pending(e: &Env, owner: &Address) -> Map<Address, IssuerAccessRequest> {
+    let now = e.ledger().timestamp();
+    let mut requests = storage::read_issuer_requests(e, owner);
+    for (issuer, request) in requests.clone().iter() {
+        if request.expires_at < now {
+            requests.remove(issuer);
+        }
+    }
+    requests
+}
@@ -1,5 +1,7 @@
 #![no_std]
 #![allow(dead_code)]
+// Entrypoint signatures are the contract ABI; argument counts are dictated by callers.
+#![allow(clippy::too_many_arguments)]
 
 // Public contract entrypoint.
 mod contract;
@@ -10,15 +12,51 @@ mod error;
 // Persistent/instance storage layout and helpers.
 mod storage;
 
+// Vault configuration snapshots for public reads.
+mod vault;
+
+// Guardian-based recovery of vault administration.
+mod recovery;
+
+// M-of-N vault administration through threshold-approved proposals.
+mod vault_admins;
+
+// Issuance fee configuration.
+mod fee;
+
 // Issuer authorization list management for vaults.
 mod issuer;
 
+// Issuer-filed access requests awaiting vault admin approval.
+mod issuer_request;
+
+// VC shares with verifiers and verification receipts.
+mod sharing;
+
 // VC status registry (valid/revoked/invalid) for issued credentials.
 mod vc_status;
 
 // Verifiable Credential payload model stored in vaults.
 mod verifiable_credential;
 
+// Resumable, batched migration of legacy storage layouts.
+mod migration;
+
+// Input validation (IDs, DIDs, payloads, fees) with configurable limits.
+mod validation;
+
+// DID document registry (verification methods, services, rotation, deactivation).
+mod did_registry;
+
+// Issuer DID ⇄ issuer address bindings (own pkh DID or attestation).
+mod issuer_did;
+
+// Trusted issuer registry: accreditation hierarchy anchored at root authorities.
+mod accreditation;
+
+// Credential schema registry (versioned content hashes, deprecation).
+mod schema;
+
 // Public interface (documented) for all external functions.
 mod acta_trait;
 
@@ -0,0 +1,177 @@
+use crate::error::ContractError;
+use crate::storage;
+use crate::vc_status::VCStatus;
+use crate::verifiable_credential::{self, VerifiableCredential};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+
+/// Maximum number of legacy entries migrated by a single `migrate` call.
+///
+/// Keeps each call under the per-transaction instruction and footprint limits,
+/// regardless of how large the legacy dataset is.
+pub const MIGRATION_BATCH_SIZE: u32 = 25;
+
+/// Progress of a resumable legacy migration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationProgress {
+    /// Legacy entries migrated so far.
+    pub migrated: u32,
+
+    /// Legacy entries still pending.
+    pub remaining: u32,
+
+    /// `true` once every entry was migrated and the legacy keys were removed.
+    pub done: bool,
+}
+
+/// Migrates the next batch of an owner's legacy vault VCs, then of its string payloads.
+///
+/// Batches are popped off the end of the legacy list, so each call rewrites a shorter list; they
+/// are stored back to front so the vault lists the IDs in legacy order. The cursor counts
+/// migrated entries.
+pub fn migrate_vault_batch(e: &Env, owner: &Address) -> MigrationProgress {
+    let mut vcs = match storage::read_legacy_vault_vcs(e, owner) {
+        Some(vcs) => vcs,
+        None => return migrate_payload_batch(e, owner),
+    };
+
+    let start = batch_start(vcs.len());
+    for vc in vcs.slice(start..).iter().rev() {
+        let vc = VerifiableCredential {
+            id: vc.id,
+            data: verifiable_credential::string_to_bytes(e, &vc.data),
+            issuance_contract: vc.issuance_contract,
+            issuer_did: vc.issuer_did,
+            credential_type: String::from_str(e, ""),
+        };
+        verifiable_credential::store_vc(e, owner, &vc);
+    }
+
+    let migrated = storage::read_legacy_vault_cursor(e, owner) + (vcs.len() - start);
+    if start == 0 {
+        storage::remove_legacy_vault_vcs(e, owner);
+        storage::remove_legacy_vault_cursor(e, owner);
+    } else {
+        vcs = vcs.slice(..start);
+        storage::write_legacy_vault_vcs(e, owner, &vcs);
+        storage::write_legacy_vault_cursor(e, owner, &migrated);
+    }
+
+    progress(migrated, migrated + start)
+}
+
+/// Rewrites the next batch of the vault's string payloads as bytes.
+///
+/// The vault is marked as migrated after the last batch.
+fn migrate_payload_batch(e: &Env, owner: &Address) -> MigrationProgress {
+    if !has_string_payloads(e, owner) {
+        panic_with_error!(e, ContractError::VCSAlreadyMigrated)
+    }
+
+    let ids = storage::read_vault_vc_ids(e, owner);
+    let total = ids.len();
+    let start = storage::read_legacy_vault_cursor(e, owner);
+    let end = batch_end(start, total);
+
+    for i in start..end {
+        let vc_id = ids.get_unchecked(i);
+        if let Some(vc) = storage::read_string_vault_vc(e, owner, &vc_id) {
+            let vc = verifiable_credential::from_string_layout(e, vc);
+            storage::write_vault_vc(e, owner, &vc_id, &vc);
+        }
+    }
+
+    if end >= total {
+        storage::write_vault_bytes_layout(e, owner);
+        storage::remove_legacy_vault_cursor(e, owner);
+    } else {
+        storage::write_legacy_vault_cursor(e, owner, &end);
+    }
+
+    progress(end, total)
+}
+
+/// Migrates the next batch of the legacy issuance status registry.
+///
+/// Batches are popped off the end of the legacy list and their revocations dropped from the
+/// legacy map, so each call rewrites shorter entries. The cursor counts migrated entries.
+pub fn migrate_issuance_batch(e: &Env) -> MigrationProgress {
+    let mut vcs = match storage::read_legacy_issuance_vcs(e) {
+        Some(vcs) => vcs,
+        None => panic_with_error!(e, ContractError::VCSAlreadyMigrated),
+    };
+
+    let mut revocations = storage::read_legacy_issuance_revocations(e);
+
+    let start = batch_start(vcs.len());
+    for vc_id in vcs.slice(start..).iter() {
+        match revocations.get(vc_id.clone()) {
+            Some(revocation) => {
+                storage::write_vc_status(e, &vc_id, &VCStatus::Revoked(revocation.date));
+                revocations.remove(vc_id);
+            }
+            None => storage::write_vc_status(e, &vc_id, &VCStatus::Valid),
+        }
+    }
+
+    let migrated = storage::read_legacy_issuance_cursor(e) + (vcs.len() - start);
+    if start == 0 {
+        storage::remove_legacy_issuance_vcs(e);
+        storage::remove_legacy_issuance_revocations(e);
+        storage::remove_legacy_issuance_cursor(e);
+    } else {
+        vcs = vcs.slice(..start);
+        storage::write_legacy_issuance_vcs(e, &vcs);
+        storage::write_legacy_issuance_revocations(e, &revocations);
+        storage::write_legacy_issuance_cursor(e, &migrated);
+    }
+
+    progress(migrated, migrated + start)
+}
+
+/// Reports migration progress for an owner's legacy vault VCs.
+pub fn vault_progress(e: &Env, owner: &Address) -> MigrationProgress {
+    match storage::read_legacy_vault_vcs(e, owner) {
+        Some(vcs) => pending_progress(storage::read_legacy_vault_cursor(e, owner), vcs.len()),
+        None if has_string_payloads(e, owner) => progress(
+            storage::read_legacy_vault_cursor(e, owner),
+            storage::read_vault_vc_ids(e, owner).len(),
+        ),
+        None => progress(0, 0),
+    }
+}
+
+/// Reports migration progress for the legacy issuance registry.
+pub fn issuance_progress(e: &Env) -> MigrationProgress {
+    match storage::read_legacy_issuance_vcs(e) {
+        Some(vcs) => pending_progress(storage::read_legacy_issuance_cursor(e), vcs.len()),
+        None => progress(0, 0),
+    }
+}
+
+/// Vaults created before payloads were bytes may still hold string payloads.
+fn has_string_payloads(e: &Env, owner: &Address) -> bool {
+    storage::has_vault_admin(e, owner) && !storage::read_vault_bytes_layout(e, owner)
+}
+
+/// Index of the first entry of the last batch of a legacy list of `len` entries.
+fn batch_start(len: u32) -> u32 {
+    len.saturating_sub(MIGRATION_BATCH_SIZE)
+}
+
+fn batch_end(start: u32, total: u32) -> u32 {
+    start.saturating_add(MIGRATION_BATCH_SIZE).min(total)
+}
+
+/// Progress of a legacy list still holding `remaining` entries after `migrated` were popped.
+fn pending_progress(migrated: u32, remaining: u32) -> MigrationProgress {
+    progress(migrated, migrated + remaining)
+}
+
+fn progress(migrated: u32, total: u32) -> MigrationProgress {
+    MigrationProgress {
+        migrated,
+        remaining: total - migrated,
+        done: migrated >= total,
+    }
+}
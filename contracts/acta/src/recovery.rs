@@ -0,0 +1,185 @@
+use crate::error::ContractError;
+use crate::{storage, vault_admins};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+
+/// Maximum number of guardians per vault.
+pub const MAX_GUARDIANS: u32 = 10;
+
+/// Seconds a recovery has to reach the threshold before another one can be started.
+pub const RECOVERY_APPROVAL_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// Guardians allowed to recover a vault's administration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianConfig {
+    /// Guardian addresses.
+    pub guardians: Vec<Address>,
+
+    /// Number of guardian approvals required.
+    pub threshold: u32,
+
+    /// Seconds between reaching the threshold and the admin change (the cancel window).
+    pub delay: u64,
+}
+
+/// Pending replacement of a vault admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Recovery {
+    /// Proposed vault admin.
+    pub new_admin: Address,
+
+    /// Guardians that approved (the initiator included).
+    pub approvals: Vec<Address>,
+
+    /// Ledger timestamp when the recovery was started.
+    pub initiated_at: u64,
+
+    /// Ledger timestamp after which the recovery lapses unless it reached the threshold.
+    pub expires_at: u64,
+
+    /// Ledger timestamp from which the recovery can complete; `None` below threshold.
+    pub executable_at: Option<u64>,
+}
+
+/// Sets the guardians (an empty list removes them); any pending recovery is dropped.
+pub fn set_guardians(
+    e: &Env,
+    owner: &Address,
+    guardians: &Vec<Address>,
+    threshold: u32,
+    delay: u64,
+) {
+    if guardians.len() > MAX_GUARDIANS {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+    for (i, guardian) in guardians.iter().enumerate() {
+        if guardians.first_index_of(&guardian) != Some(i as u32) {
+            panic_with_error!(e, ContractError::InvalidArgument)
+        }
+    }
+    if guardians.is_empty() {
+        storage::remove_guardians(e, owner);
+        storage::remove_recovery(e, owner);
+        return;
+    }
+    // A zero delay would leave the current admin no window to cancel.
+    if threshold == 0 || threshold > guardians.len() || delay == 0 {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+
+    let config = GuardianConfig {
+        guardians: guardians.clone(),
+        threshold,
+        delay,
+    };
+    storage::write_guardians(e, owner, &config);
+    storage::remove_recovery(e, owner);
+}
+
+/// Starts a recovery towards `new_admin` on behalf of a guardian.
+///
+/// Only replaces a pending recovery that lapsed below the threshold: a single guardian can
+/// neither wipe the others' approvals nor park a recovery for longer than the approval window.
+/// A recovery in its delay can only be cancelled.
+pub fn initiate(e: &Env, owner: &Address, guardian: &Address, new_admin: &Address) {
+    let config = read_config(e, owner);
+    validate_guardian(e, &config, guardian);
+    if let Some(pending) = storage::read_recovery(e, owner) {
+        if !is_lapsed(e, &pending) {
+            panic_with_error!(e, ContractError::InvalidArgument)
+        }
+    }
+
+    let now = e.ledger().timestamp();
+    let mut recovery = Recovery {
+        new_admin: new_admin.clone(),
+        approvals: Vec::new(e),
+        initiated_at: now,
+        expires_at: now.saturating_add(RECOVERY_APPROVAL_WINDOW),
+        executable_at: None,
+    };
+    add_approval(e, &config, &mut recovery, guardian);
+    storage::write_recovery(e, owner, &recovery);
+}
+
+/// Adds a guardian approval; the delay starts once the threshold is reached.
+///
+/// The guardian names the `new_admin` it approves, so an approval cannot land on a recovery
+/// that was replaced in the meantime.
+pub fn approve(e: &Env, owner: &Address, guardian: &Address, new_admin: &Address) {
+    let config = read_config(e, owner);
+    validate_guardian(e, &config, guardian);
+
+    let mut recovery = read_pending(e, owner);
+    if recovery.new_admin != *new_admin {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    if is_lapsed(e, &recovery) {
+        panic_with_error!(e, ContractError::Expired)
+    }
+    add_approval(e, &config, &mut recovery, guardian);
+    storage::write_recovery(e, owner, &recovery);
+}
+
+/// Drops the pending recovery before it becomes executable.
+///
+/// Once the delay has elapsed the recovery can no longer be cancelled, only completed.
+pub fn cancel(e: &Env, owner: &Address) {
+    let recovery = read_pending(e, owner);
+    if let Some(at) = recovery.executable_at {
+        if e.ledger().timestamp() >= at {
+            panic_with_error!(e, ContractError::Expired)
+        }
+    }
+    storage::remove_recovery(e, owner);
+}
+
+/// Replaces the vault admin once the threshold was reached and the delay has elapsed.
+///
+/// Like handing the vault back with `SetVaultAdmin`, this clears an M-of-N admin set.
+pub fn complete(e: &Env, owner: &Address) {
+    let recovery = read_pending(e, owner);
+    match recovery.executable_at {
+        Some(at) if e.ledger().timestamp() >= at => {}
+        _ => panic_with_error!(e, ContractError::RecoveryNotReady),
+    }
+
+    storage::write_vault_admin(e, owner, &recovery.new_admin);
+    vault_admins::clear(e, owner);
+    storage::remove_recovery(e, owner);
+}
+
+fn add_approval(e: &Env, config: &GuardianConfig, recovery: &mut Recovery, guardian: &Address) {
+    if !recovery.approvals.contains(guardian) {
+        recovery.approvals.push_back(guardian.clone());
+    }
+    if recovery.executable_at.is_none() && recovery.approvals.len() >= config.threshold {
+        recovery.executable_at = Some(e.ledger().timestamp().saturating_add(config.delay));
+    }
+}
+
+/// Whether the recovery ran out of time to reach the threshold.
+fn is_lapsed(e: &Env, recovery: &Recovery) -> bool {
+    recovery.executable_at.is_none() && e.ledger().timestamp() > recovery.expires_at
+}
+
+fn read_config(e: &Env, owner: &Address) -> GuardianConfig {
+    match storage::read_guardians(e, owner) {
+        Some(config) => config,
+        None => panic_with_error!(e, ContractError::NotFound),
+    }
+}
+
+fn read_pending(e: &Env, owner: &Address) -> Recovery {
+    match storage::read_recovery(e, owner) {
+        Some(recovery) => recovery,
+        None => panic_with_error!(e, ContractError::NotFound),
+    }
+}
+
+fn validate_guardian(e: &Env, config: &GuardianConfig, guardian: &Address) {
+    if !config.guardians.contains(guardian) {
+        panic_with_error!(e, ContractError::Unauthorized)
+    }
+}
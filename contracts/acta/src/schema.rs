@@ -0,0 +1,107 @@
+use crate::error::ContractError;
+use crate::{storage, validation};
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, String};
+
+/// Lifecycle status of a schema version.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SchemaStatus {
+    Active,
+    Deprecated,
+}
+
+/// Published version of a credential schema.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Schema {
+    /// Schema ID, shared by all its versions.
+    pub id: String,
+
+    /// Version number; each new version is greater than the previous one.
+    pub version: u32,
+
+    /// Address allowed to publish and deprecate versions.
+    pub author: Address,
+
+    /// SHA-256 of the JSON schema document.
+    pub hash: BytesN<32>,
+
+    /// Deprecated versions can no longer be issued against.
+    pub status: SchemaStatus,
+
+    /// Ledger timestamp of publication.
+    pub published: u64,
+}
+
+/// Schema version a credential was issued against.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaRef {
+    pub id: String,
+    pub version: u32,
+}
+
+/// Publishes a new version of `id`; the first publisher becomes its author.
+pub fn publish(e: &Env, author: &Address, id: &String, version: u32, hash: &BytesN<32>) {
+    validation::validate_id(e, id);
+    let previous = match storage::read_schema_latest(e, id) {
+        Some(latest) => {
+            if read(e, id, latest).author != *author {
+                panic_with_error!(e, ContractError::Unauthorized)
+            }
+            latest
+        }
+        None => 0,
+    };
+    if version <= previous {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+
+    let schema = Schema {
+        id: id.clone(),
+        version,
+        author: author.clone(),
+        hash: hash.clone(),
+        status: SchemaStatus::Active,
+        published: e.ledger().timestamp(),
+    };
+    storage::write_schema(e, &schema);
+    storage::write_schema_latest(e, id, &version);
+}
+
+pub fn deprecate(e: &Env, author: &Address, id: &String, version: u32) {
+    let mut schema = read(e, id, version);
+    if schema.author != *author {
+        panic_with_error!(e, ContractError::Unauthorized)
+    }
+    schema.status = SchemaStatus::Deprecated;
+    storage::write_schema(e, &schema);
+}
+
+/// Reads a schema version, or its latest version when `version` is `None`.
+pub fn get(e: &Env, id: &String, version: Option<u32>) -> Schema {
+    let version = match version.or_else(|| storage::read_schema_latest(e, id)) {
+        Some(version) => version,
+        None => panic_with_error!(e, ContractError::SchemaNotFound),
+    };
+    read(e, id, version)
+}
+
+/// Resolves the schema version new credentials of `id` are issued against (its latest).
+pub fn resolve_for_issuance(e: &Env, id: &String) -> SchemaRef {
+    let schema = get(e, id, None);
+    if schema.status == SchemaStatus::Deprecated {
+        panic_with_error!(e, ContractError::SchemaDeprecated)
+    }
+    SchemaRef {
+        id: schema.id,
+        version: schema.version,
+    }
+}
+
+fn read(e: &Env, id: &String, version: u32) -> Schema {
+    match storage::read_schema(e, id, version) {
+        Some(schema) => schema,
+        None => panic_with_error!(e, ContractError::SchemaNotFound),
+    }
+}
@@ -0,0 +1,99 @@
+use crate::error::ContractError;
+use crate::storage;
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, Map, String};
+
+/// Maximum number of verifiers a VC can be shared with at once.
+pub const MAX_VC_SHARES: u32 = 20;
+
+/// Record of a verifier checking a VC's status.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationReceipt {
+    /// Verifier that requested the receipt.
+    pub verifier: Address,
+
+    /// Vault owner holding the VC at verification time.
+    pub owner: Address,
+
+    /// Verified VC identifier.
+    pub vc_id: String,
+
+    /// Status reported by `verify_vc` (`valid`, `revoked` or `invalid`).
+    pub status: String,
+
+    /// Ledger timestamp of the verification.
+    pub verified_at: u64,
+}
+
+/// Grants `verifier` access to `owner`'s `vc_id` until `expires_at` (replaces an earlier grant).
+///
+/// Emits `("vc_share", owner)` with the VC ID, verifier and expiry.
+pub fn share(e: &Env, owner: &Address, vc_id: &String, verifier: &Address, expires_at: u64) {
+    if storage::read_vault_vc(e, owner, vc_id).is_none() {
+        panic_with_error!(e, ContractError::VCNotFound)
+    }
+    if expires_at <= e.ledger().timestamp() {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+
+    let mut shares = read_active(e, owner, vc_id);
+    shares.set(verifier.clone(), expires_at);
+    if shares.len() > MAX_VC_SHARES {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+    storage::write_vc_shares(e, owner, vc_id, &shares);
+
+    e.events().publish(
+        (symbol_short!("vc_share"), owner.clone()),
+        (vc_id.clone(), verifier.clone(), expires_at),
+    );
+}
+
+pub fn unshare(e: &Env, owner: &Address, vc_id: &String, verifier: &Address) {
+    let mut shares = storage::read_vc_shares(e, owner, vc_id);
+    if shares.remove(verifier.clone()).is_none() {
+        panic_with_error!(e, ContractError::NotFound)
+    }
+    storage::write_vc_shares(e, owner, vc_id, &shares);
+}
+
+/// Expiry of `verifier`'s access to the VC, unless it has lapsed.
+pub fn share_expiry(e: &Env, owner: &Address, vc_id: &String, verifier: &Address) -> Option<u64> {
+    read_active(e, owner, vc_id).get(verifier.clone())
+}
+
+/// Stores `verifier`'s latest receipt for `vc_id` and emits `("receipt", verifier)`.
+pub fn record_receipt(
+    e: &Env,
+    verifier: &Address,
+    owner: &Address,
+    vc_id: &String,
+    status: &String,
+) -> VerificationReceipt {
+    let receipt = VerificationReceipt {
+        verifier: verifier.clone(),
+        owner: owner.clone(),
+        vc_id: vc_id.clone(),
+        status: status.clone(),
+        verified_at: e.ledger().timestamp(),
+    };
+    storage::write_verification_receipt(e, verifier, vc_id, &receipt);
+
+    e.events().publish(
+        (symbol_short!("receipt"), verifier.clone()),
+        (owner.clone(), vc_id.clone(), status.clone()),
+    );
+    receipt
+}
+
+/// Reads the VC's grants, dropping the lapsed ones.
+fn read_active(e: &Env, owner: &Address, vc_id: &String) -> Map<Address, u64> {
+    let now = e.ledger().timestamp();
+    let mut shares = storage::read_vc_shares(e, owner, vc_id);
+    for (verifier, expires_at) in shares.clone().iter() {
+        if expires_at < now {
+            shares.remove(verifier);
+        }
+    }
+    shares
+}
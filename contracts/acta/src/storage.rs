@@ -1,6 +1,26 @@
+use crate::accreditation::Accreditation;
+use crate::did_registry::DidDocument;
+use crate::error::ContractError;
+use crate::fee::{
+    FeeOperation, FeePayment, FeeShare, FeeTier, IssuerFee, RefundPolicy, Sponsorship,
+};
+use crate::issuer::IssuerTerms;
+use crate::issuer_did::IssuerDidBinding;
+use crate::issuer_request::IssuerAccessRequest;
+use crate::recovery::{GuardianConfig, Recovery};
+use crate::schema::{Schema, SchemaRef};
+use crate::sharing::VerificationReceipt;
+use crate::vault_admins::{AdminSet, Proposal};
+use crate::validation::{ValidationLimits, DEFAULT_LIMITS};
 use crate::vc_status::VCStatus;
-use crate::verifiable_credential::VerifiableCredential;
-use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+use crate::verifiable_credential::{
+    self, BaselineVerifiableCredential, LegacyVerifiableCredential, PayloadUpload,
+    StringVerifiableCredential, VerifiableCredential, BASELINE_VC_FIELDS, PAYLOAD_CHUNK_SIZE,
+};
+use soroban_sdk::{
+    contracttype, panic_with_error, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol,
+    Val, Vec,
+};
 
 /// Unified storage keys.
 ///
@@ -23,18 +43,39 @@ pub enum DataKey {
     FeeDest,                // Address
     FeeAmount,              // i128
 
+    // Fee schedule (see FeeKey)
+    Fee(FeeKey),
+
+    // Input validation limits (instance storage)
+    ValidationLimits,       // ValidationLimits
+
     // -----------------
     // Vault (per owner)
     // -----------------
     VaultAdmin(Address),    // Address
     VaultDid(Address),      // String
     VaultRevoked(Address),  // bool
+    VaultClosing(Address),  // bool (closure in progress)
+    VaultRotation(Address), // Address (new owner, rotation in progress)
+    VaultRotationSource(Address), // Address (old owner, while this vault is a rotation target)
+    VaultAlias(Address),    // Address (new owner after a completed rotation)
+
+    // Issuer set per owner (persistent)
+    VaultIssuerSet(Address), // Map<Address, bool>
+    VaultIssuerTerms(Address, Address), // IssuerTerms (owner, issuer)
+    VaultIssuerRequests(Address), // Map<Address, IssuerAccessRequest> (by issuer)
+
+    // Guardian recovery per owner (persistent)
+    VaultGuardians(Address), // GuardianConfig
+    VaultRecovery(Address),  // Recovery
 
-    // Issuer list per owner (persistent)
-    VaultIssuers(Address),  // Vec<Address>
+    // M-of-N vault administration (persistent)
+    VaultAdminSet(Address),     // AdminSet
+    VaultProposals(Address),    // Map<u32, Proposal>
+    VaultProposalSeq(Address),  // u32
 
-    // VC payload per owner (persistent)
-    VaultVC(Address, String), // VerifiableCredential
+    // VC payload per owner (see CredentialKey)
+    Credential(CredentialKey),
     VaultVCIds(Address),      // Vec<String>
 
     // -----------------
@@ -42,13 +83,77 @@ pub enum DataKey {
     // -----------------
     VCStatus(String),       // VCStatus
     VCOwner(String),        // Address
+    VCIssuer(String),       // Address
+    VCSchema(String),       // SchemaRef
+    VCPayloadHash(String),  // BytesN<32> (SHA-256 of the issued payload)
+    VCCommitment(String),   // BytesN<32> (issuer's SHA-256 of the plaintext)
+
+    // Credential schema registry (persistent)
+    Schema(String, u32),    // Schema (by ID and version)
+    SchemaLatest(String),   // u32
+
+    // -----------------
+    // DID registry
+    // -----------------
+    DidDocument(String),    // DidDocument
+    DidAttestation(String), // Address (controller the admin vouched for)
+
+    // Issuer DID bindings (persistent)
+    IssuerDid(Address, String), // IssuerDidBinding
+
+    // Trusted issuer registry (persistent)
+    Accreditation(Address),     // Accreditation
 
     // -----------------
     // Legacy keys (for migration)
     // -----------------
     LegacyIssuanceRevocations, // Map<String, LegacyRevocation>
     LegacyIssuanceVCs,         // Vec<String>
-    LegacyVaultVCs(Address),   // Vec<VerifiableCredential>
+    LegacyVaultVCs(Address),   // Vec<LegacyVerifiableCredential>
+    VaultIssuers(Address),     // Vec<Address> (converted to VaultIssuerSet on first write)
+    VaultVC(Address, String),  // Baseline or StringVerifiableCredential (converted on read)
+
+    // Resumable migration cursors (persistent)
+    LegacyIssuanceCursor,        // u32
+    LegacyVaultCursor(Address),  // u32
+}
+
+/// Vault VC keys, nested under `DataKey::Credential` (persistent storage).
+#[derive(Clone)]
+#[contracttype]
+pub enum CredentialKey {
+    Record(Address, String),          // VerifiableCredential (first chunk of the payload)
+    Chunks(Address, String),          // u32 (further chunks)
+    Chunk(Address, String, u32),      // Bytes
+    Upload(Address, String),          // PayloadUpload
+    UploadPart(Address, String, u32), // Bytes
+    Uploads(Address),                 // Vec<String> (VC IDs with a pending upload)
+    Shares(Address, String),          // Map<Address, u64> (verifier -> expiry)
+    Receipt(Address, String),         // VerificationReceipt (keyed by verifier)
+    BytesLayout(Address),             // bool (vault holds no string payloads)
+}
+
+/// Fee schedule keys, nested under `DataKey::Fee`.
+#[derive(Clone)]
+#[contracttype]
+pub enum FeeKey {
+    // Instance storage
+    Manager,                // Address
+    Tiers,                  // Vec<FeeTier>
+    Shares,                 // Vec<FeeShare>
+    Operation(FeeOperation), // i128 price per operation
+    RefundPolicy,           // RefundPolicy
+
+    // Persistent storage
+    CredentialType(String), // i128
+    Issuer(Address),        // IssuerFee
+    IssuerIssued(Address),  // u32 (issuances across all vaults)
+    Credit(Address, Address), // i128 (issuer, token) prepaid credit held in escrow
+    Sponsorship(Address),   // Sponsorship (by beneficiary issuer)
+    Charged(Address, Address), // i128 (account, token) cumulative fees charged
+    Collected(Address, Address), // i128 (recipient, token) cumulative fees received
+    Payment(String),        // FeePayment (by VC ID) escrowed during the refund window
+    IssuerRefunds(Address), // u32 refunds received
 }
 
 #[contracttype]
@@ -67,7 +172,10 @@ pub fn has_contract_admin(e: &Env) -> bool {
 }
 
 pub fn read_contract_admin(e: &Env) -> Address {
-    e.storage().instance().get(&DataKey::ContractAdmin).unwrap()
+    e.storage()
+        .instance()
+        .get(&DataKey::ContractAdmin)
+        .unwrap_or_else(|| panic_with_error!(e, ContractError::NotInitialized))
 }
 
 pub fn write_contract_admin(e: &Env, admin: &Address) {
@@ -87,10 +195,10 @@ pub fn write_default_issuer_did(e: &Env, did: &String) {
 // -----------------
 
 pub fn read_fee_enabled(e: &Env) -> bool {
-    match e.storage().instance().get(&DataKey::FeeEnabled) {
-        Some(v) => v,
-        None => false,
-    }
+    e.storage()
+        .instance()
+        .get(&DataKey::FeeEnabled)
+        .unwrap_or_default()
 }
 
 pub fn write_fee_enabled(e: &Env, enabled: &bool) {
@@ -102,7 +210,10 @@ pub fn write_fee_token_contract(e: &Env, addr: &Address) {
 }
 
 pub fn read_fee_token_contract(e: &Env) -> Address {
-    e.storage().instance().get(&DataKey::FeeTokenContract).unwrap()
+    e.storage()
+        .instance()
+        .get(&DataKey::FeeTokenContract)
+        .unwrap_or_else(|| panic_with_error!(e, ContractError::FeeNotConfigured))
 }
 
 pub fn write_fee_dest(e: &Env, addr: &Address) {
@@ -110,7 +221,10 @@ pub fn write_fee_dest(e: &Env, addr: &Address) {
 }
 
 pub fn read_fee_dest(e: &Env) -> Address {
-    e.storage().instance().get(&DataKey::FeeDest).unwrap()
+    e.storage()
+        .instance()
+        .get(&DataKey::FeeDest)
+        .unwrap_or_else(|| panic_with_error!(e, ContractError::FeeNotConfigured))
 }
 
 pub fn write_fee_amount(e: &Env, amount: &i128) {
@@ -118,7 +232,245 @@ pub fn write_fee_amount(e: &Env, amount: &i128) {
 }
 
 pub fn read_fee_amount(e: &Env) -> i128 {
-    e.storage().instance().get(&DataKey::FeeAmount).unwrap()
+    e.storage()
+        .instance()
+        .get(&DataKey::FeeAmount)
+        .unwrap_or_else(|| panic_with_error!(e, ContractError::FeeNotConfigured))
+}
+
+/// Fee config is complete only when token, destination and amount are all set.
+pub fn has_fee_config(e: &Env) -> bool {
+    let instance = e.storage().instance();
+    instance.has(&DataKey::FeeTokenContract)
+        && instance.has(&DataKey::FeeDest)
+        && instance.has(&DataKey::FeeAmount)
+}
+
+pub fn read_fee_manager(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Fee(FeeKey::Manager))
+}
+
+pub fn write_fee_manager(e: &Env, manager: &Address) {
+    e.storage().instance().set(&DataKey::Fee(FeeKey::Manager), manager);
+}
+
+pub fn remove_fee_manager(e: &Env) {
+    e.storage().instance().remove(&DataKey::Fee(FeeKey::Manager));
+}
+
+pub fn read_fee_tiers(e: &Env) -> Vec<FeeTier> {
+    e.storage()
+        .instance()
+        .get(&DataKey::Fee(FeeKey::Tiers))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+pub fn write_fee_tiers(e: &Env, tiers: &Vec<FeeTier>) {
+    e.storage().instance().set(&DataKey::Fee(FeeKey::Tiers), tiers);
+}
+
+pub fn read_fee_shares(e: &Env) -> Vec<FeeShare> {
+    e.storage()
+        .instance()
+        .get(&DataKey::Fee(FeeKey::Shares))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+pub fn write_fee_shares(e: &Env, shares: &Vec<FeeShare>) {
+    e.storage().instance().set(&DataKey::Fee(FeeKey::Shares), shares);
+}
+
+pub fn read_credential_type_fee(e: &Env, credential_type: &String) -> Option<i128> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::CredentialType(credential_type.clone())))
+}
+
+pub fn write_credential_type_fee(e: &Env, credential_type: &String, amount: &i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::CredentialType(credential_type.clone())), amount)
+}
+
+pub fn remove_credential_type_fee(e: &Env, credential_type: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Fee(FeeKey::CredentialType(credential_type.clone())));
+}
+
+pub fn read_issuer_fee(e: &Env, issuer: &Address) -> Option<IssuerFee> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::Issuer(issuer.clone())))
+}
+
+pub fn write_issuer_fee(e: &Env, issuer: &Address, fee: &IssuerFee) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::Issuer(issuer.clone())), fee)
+}
+
+pub fn remove_issuer_fee(e: &Env, issuer: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Fee(FeeKey::Issuer(issuer.clone())));
+}
+
+pub fn read_issuer_issued(e: &Env, issuer: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::IssuerIssued(issuer.clone())))
+        .unwrap_or(0)
+}
+
+pub fn write_issuer_issued(e: &Env, issuer: &Address, issued: &u32) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::IssuerIssued(issuer.clone())), issued)
+}
+
+pub fn read_fee_credit(e: &Env, issuer: &Address, token: &Address) -> Option<i128> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::Credit(issuer.clone(), token.clone())))
+}
+
+pub fn write_fee_credit(e: &Env, issuer: &Address, token: &Address, balance: &i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::Credit(issuer.clone(), token.clone())), balance)
+}
+
+pub fn remove_fee_credit(e: &Env, issuer: &Address, token: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Fee(FeeKey::Credit(issuer.clone(), token.clone())));
+}
+
+pub fn read_operation_fee(e: &Env, operation: FeeOperation) -> Option<i128> {
+    e.storage()
+        .instance()
+        .get(&DataKey::Fee(FeeKey::Operation(operation)))
+}
+
+pub fn write_operation_fee(e: &Env, operation: FeeOperation, amount: &i128) {
+    e.storage()
+        .instance()
+        .set(&DataKey::Fee(FeeKey::Operation(operation)), amount)
+}
+
+pub fn remove_operation_fee(e: &Env, operation: FeeOperation) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::Fee(FeeKey::Operation(operation)));
+}
+
+pub fn read_fees_charged(e: &Env, account: &Address, token: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::Charged(account.clone(), token.clone())))
+        .unwrap_or(0)
+}
+
+pub fn write_fees_charged(e: &Env, account: &Address, token: &Address, amount: &i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::Charged(account.clone(), token.clone())), amount)
+}
+
+pub fn read_fees_collected(e: &Env, recipient: &Address, token: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::Collected(recipient.clone(), token.clone())))
+        .unwrap_or(0)
+}
+
+pub fn write_fees_collected(e: &Env, recipient: &Address, token: &Address, amount: &i128) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::Collected(recipient.clone(), token.clone())), amount)
+}
+
+pub fn read_refund_policy(e: &Env) -> Option<RefundPolicy> {
+    e.storage()
+        .instance()
+        .get(&DataKey::Fee(FeeKey::RefundPolicy))
+}
+
+pub fn write_refund_policy(e: &Env, policy: &RefundPolicy) {
+    e.storage()
+        .instance()
+        .set(&DataKey::Fee(FeeKey::RefundPolicy), policy)
+}
+
+pub fn remove_refund_policy(e: &Env) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::Fee(FeeKey::RefundPolicy));
+}
+
+pub fn read_fee_payment(e: &Env, vc_id: &String) -> Option<FeePayment> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::Payment(vc_id.clone())))
+}
+
+pub fn write_fee_payment(e: &Env, vc_id: &String, payment: &FeePayment) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::Payment(vc_id.clone())), payment)
+}
+
+pub fn remove_fee_payment(e: &Env, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Fee(FeeKey::Payment(vc_id.clone())));
+}
+
+pub fn read_issuer_refunds(e: &Env, issuer: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::IssuerRefunds(issuer.clone())))
+        .unwrap_or(0)
+}
+
+pub fn write_issuer_refunds(e: &Env, issuer: &Address, refunds: &u32) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::IssuerRefunds(issuer.clone())), refunds)
+}
+
+pub fn read_sponsorship(e: &Env, beneficiary: &Address) -> Option<Sponsorship> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Fee(FeeKey::Sponsorship(beneficiary.clone())))
+}
+
+pub fn write_sponsorship(e: &Env, beneficiary: &Address, sponsorship: &Sponsorship) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Fee(FeeKey::Sponsorship(beneficiary.clone())), sponsorship)
+}
+
+pub fn remove_sponsorship(e: &Env, beneficiary: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Fee(FeeKey::Sponsorship(beneficiary.clone())));
+}
+
+// -----------------
+// Validation limits
+// -----------------
+
+pub fn read_validation_limits(e: &Env) -> ValidationLimits {
+    e.storage()
+        .instance()
+        .get(&DataKey::ValidationLimits)
+        .unwrap_or(DEFAULT_LIMITS)
+}
+
+pub fn write_validation_limits(e: &Env, limits: &ValidationLimits) {
+    e.storage().instance().set(&DataKey::ValidationLimits, limits);
 }
 
 // -----------------
@@ -130,7 +482,10 @@ pub fn has_vault_admin(e: &Env, owner: &Address) -> bool {
 }
 
 pub fn read_vault_admin(e: &Env, owner: &Address) -> Address {
-    e.storage().instance().get(&DataKey::VaultAdmin(owner.clone())).unwrap()
+    e.storage()
+        .instance()
+        .get(&DataKey::VaultAdmin(owner.clone()))
+        .unwrap_or_else(|| panic_with_error!(e, ContractError::VaultNotInitialized))
 }
 
 pub fn write_vault_admin(e: &Env, owner: &Address, admin: &Address) {
@@ -146,41 +501,487 @@ pub fn read_vault_did(e: &Env, owner: &Address) -> Option<String> {
 }
 
 pub fn read_vault_revoked(e: &Env, owner: &Address) -> bool {
-    e.storage().instance().get(&DataKey::VaultRevoked(owner.clone())).unwrap()
+    e.storage()
+        .instance()
+        .get(&DataKey::VaultRevoked(owner.clone()))
+        .unwrap_or_else(|| panic_with_error!(e, ContractError::VaultNotInitialized))
 }
 
 pub fn write_vault_revoked(e: &Env, owner: &Address, revoked: &bool) {
     e.storage().instance().set(&DataKey::VaultRevoked(owner.clone()), revoked);
 }
 
+pub fn read_vault_closing(e: &Env, owner: &Address) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::VaultClosing(owner.clone()))
+        .unwrap_or(false)
+}
+
+pub fn write_vault_closing(e: &Env, owner: &Address, closing: &bool) {
+    e.storage().instance().set(&DataKey::VaultClosing(owner.clone()), closing);
+}
+
+pub fn read_vault_rotation(e: &Env, owner: &Address) -> Option<Address> {
+    e.storage().instance().get(&DataKey::VaultRotation(owner.clone()))
+}
+
+pub fn write_vault_rotation(e: &Env, owner: &Address, new_owner: &Address) {
+    e.storage().instance().set(&DataKey::VaultRotation(owner.clone()), new_owner);
+}
+
+pub fn remove_vault_rotation(e: &Env, owner: &Address) {
+    e.storage().instance().remove(&DataKey::VaultRotation(owner.clone()));
+}
+
+pub fn read_vault_rotation_source(e: &Env, owner: &Address) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get(&DataKey::VaultRotationSource(owner.clone()))
+}
+
+pub fn write_vault_rotation_source(e: &Env, owner: &Address, old_owner: &Address) {
+    e.storage()
+        .instance()
+        .set(&DataKey::VaultRotationSource(owner.clone()), old_owner);
+}
+
+pub fn remove_vault_rotation_source(e: &Env, owner: &Address) {
+    e.storage()
+        .instance()
+        .remove(&DataKey::VaultRotationSource(owner.clone()));
+}
+
+pub fn read_vault_alias(e: &Env, owner: &Address) -> Option<Address> {
+    e.storage().persistent().get(&DataKey::VaultAlias(owner.clone()))
+}
+
+pub fn write_vault_alias(e: &Env, owner: &Address, new_owner: &Address) {
+    e.storage().persistent().set(&DataKey::VaultAlias(owner.clone()), new_owner);
+}
+
+pub fn remove_vault_alias(e: &Env, owner: &Address) {
+    e.storage().persistent().remove(&DataKey::VaultAlias(owner.clone()));
+}
+
+/// Removes all vault metadata; the owner no longer has a vault afterwards.
+pub fn remove_vault_metadata(e: &Env, owner: &Address) {
+    let instance = e.storage().instance();
+    instance.remove(&DataKey::VaultAdmin(owner.clone()));
+    instance.remove(&DataKey::VaultDid(owner.clone()));
+    instance.remove(&DataKey::VaultRevoked(owner.clone()));
+    instance.remove(&DataKey::VaultClosing(owner.clone()));
+    remove_vault_bytes_layout(e, owner);
+}
+
 // -----------------
 // Vault issuers (persistent)
 // -----------------
 
-pub fn read_vault_issuers(e: &Env, owner: &Address) -> Vec<Address> {
-    e.storage().persistent().get(&DataKey::VaultIssuers(owner.clone())).unwrap()
+/// Reads the vault's issuer set, falling back to the legacy `Vec<Address>` list.
+pub fn read_vault_issuers(e: &Env, owner: &Address) -> Map<Address, bool> {
+    if let Some(issuers) = e
+        .storage()
+        .persistent()
+        .get(&DataKey::VaultIssuerSet(owner.clone()))
+    {
+        return issuers;
+    }
+
+    let legacy: Vec<Address> = e
+        .storage()
+        .persistent()
+        .get(&DataKey::VaultIssuers(owner.clone()))
+        .unwrap_or_else(|| panic_with_error!(e, ContractError::VaultNotInitialized));
+    let mut issuers = Map::new(e);
+    for issuer in legacy.iter() {
+        issuers.set(issuer, true);
+    }
+    issuers
+}
+
+pub fn write_vault_issuers(e: &Env, owner: &Address, issuers: &Map<Address, bool>) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultIssuerSet(owner.clone()), issuers);
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultIssuers(owner.clone()));
+}
+
+pub fn remove_vault_issuers(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultIssuerSet(owner.clone()));
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultIssuers(owner.clone()));
+}
+
+pub fn read_issuer_terms(e: &Env, owner: &Address, issuer: &Address) -> Option<IssuerTerms> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultIssuerTerms(owner.clone(), issuer.clone()))
+}
+
+pub fn write_issuer_terms(e: &Env, owner: &Address, issuer: &Address, terms: &IssuerTerms) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultIssuerTerms(owner.clone(), issuer.clone()), terms)
+}
+
+pub fn remove_issuer_terms(e: &Env, owner: &Address, issuer: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultIssuerTerms(owner.clone(), issuer.clone()));
+}
+
+pub fn read_issuer_requests(e: &Env, owner: &Address) -> Map<Address, IssuerAccessRequest> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultIssuerRequests(owner.clone()))
+        .unwrap_or_else(|| Map::new(e))
+}
+pub fn write_issuer_requests(
+    e: &Env,
+    owner: &Address,
+    requests: &Map<Address, IssuerAccessRequest>,
+) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultIssuerRequests(owner.clone()), requests)
 }
 
-pub fn write_vault_issuers(e: &Env, owner: &Address, issuers: &Vec<Address>) {
-    e.storage().persistent().set(&DataKey::VaultIssuers(owner.clone()), issuers)
+pub fn remove_issuer_requests(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultIssuerRequests(owner.clone()));
+}
+
+// -----------------
+// Guardian recovery (persistent)
+// -----------------
+
+pub fn read_guardians(e: &Env, owner: &Address) -> Option<GuardianConfig> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultGuardians(owner.clone()))
+}
+
+pub fn write_guardians(e: &Env, owner: &Address, config: &GuardianConfig) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultGuardians(owner.clone()), config)
+}
+
+pub fn remove_guardians(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultGuardians(owner.clone()));
+}
+
+pub fn read_recovery(e: &Env, owner: &Address) -> Option<Recovery> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultRecovery(owner.clone()))
+}
+
+pub fn write_recovery(e: &Env, owner: &Address, recovery: &Recovery) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultRecovery(owner.clone()), recovery)
+}
+
+pub fn remove_recovery(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultRecovery(owner.clone()));
+}
+
+// -----------------
+// M-of-N vault administration (persistent)
+// -----------------
+
+pub fn read_vault_admin_set(e: &Env, owner: &Address) -> Option<AdminSet> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultAdminSet(owner.clone()))
+}
+
+pub fn write_vault_admin_set(e: &Env, owner: &Address, set: &AdminSet) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultAdminSet(owner.clone()), set)
+}
+
+pub fn remove_vault_admin_set(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultAdminSet(owner.clone()));
+}
+
+pub fn read_vault_proposals(e: &Env, owner: &Address) -> Map<u32, Proposal> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultProposals(owner.clone()))
+        .unwrap_or_else(|| Map::new(e))
+}
+
+pub fn write_vault_proposals(e: &Env, owner: &Address, proposals: &Map<u32, Proposal>) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultProposals(owner.clone()), proposals)
+}
+
+pub fn remove_vault_proposals(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VaultProposals(owner.clone()));
+}
+
+pub fn read_vault_proposal_seq(e: &Env, owner: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VaultProposalSeq(owner.clone()))
+        .unwrap_or(0)
+}
+
+pub fn write_vault_proposal_seq(e: &Env, owner: &Address, seq: &u32) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultProposalSeq(owner.clone()), seq)
 }
 
 // -----------------
 // Vault VC payloads (persistent)
 // -----------------
 
+/// Writes the VC, splitting payloads above `PAYLOAD_CHUNK_SIZE` across chunk entries.
 pub fn write_vault_vc(e: &Env, owner: &Address, vc_id: &String, vc: &VerifiableCredential) {
-    e.storage().persistent().set(&DataKey::VaultVC(owner.clone(), vc_id.clone()), vc)
+    let previous = read_vault_vc_chunks(e, owner, vc_id);
+    let len = vc.data.len();
+    let mut head = vc.clone();
+    head.data = vc.data.slice(0..len.min(PAYLOAD_CHUNK_SIZE));
+
+    let mut chunks = 0;
+    let mut start = PAYLOAD_CHUNK_SIZE;
+    while start < len {
+        let chunk = vc.data.slice(start..len.min(start + PAYLOAD_CHUNK_SIZE));
+        e.storage().persistent().set(&chunk_key(owner, vc_id, chunks), &chunk);
+        chunks += 1;
+        start += PAYLOAD_CHUNK_SIZE;
+    }
+    for index in chunks..previous {
+        e.storage().persistent().remove(&chunk_key(owner, vc_id, index));
+    }
+
+    let chunks_key = DataKey::Credential(CredentialKey::Chunks(owner.clone(), vc_id.clone()));
+    if chunks > 0 {
+        e.storage().persistent().set(&chunks_key, &chunks);
+    } else {
+        e.storage().persistent().remove(&chunks_key);
+    }
+    e.storage().persistent().set(&record_key(owner, vc_id), &head);
+    e.storage().persistent().remove(&DataKey::VaultVC(owner.clone(), vc_id.clone()));
 }
 
+/// Reads the VC with its payload reassembled, converting string payloads.
 pub fn read_vault_vc(e: &Env, owner: &Address, vc_id: &String) -> Option<VerifiableCredential> {
-    e.storage().persistent().get(&DataKey::VaultVC(owner.clone(), vc_id.clone()))
+    let mut vc: VerifiableCredential = match e.storage().persistent().get(&record_key(owner, vc_id))
+    {
+        Some(vc) => vc,
+        None => {
+            return read_string_vault_vc(e, owner, vc_id)
+                .map(|vc| verifiable_credential::from_string_layout(e, vc))
+        }
+    };
+    for index in 0..read_vault_vc_chunks(e, owner, vc_id) {
+        let chunk: Bytes = e
+            .storage()
+            .persistent()
+            .get(&chunk_key(owner, vc_id, index))
+            .unwrap_or_else(|| Bytes::new(e));
+        vc.data.append(&chunk);
+    }
+    Some(vc)
 }
 
 pub fn remove_vault_vc(e: &Env, owner: &Address, vc_id: &String) {
+    for index in 0..read_vault_vc_chunks(e, owner, vc_id) {
+        e.storage().persistent().remove(&chunk_key(owner, vc_id, index));
+    }
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Credential(CredentialKey::Chunks(owner.clone(), vc_id.clone())));
+    e.storage().persistent().remove(&record_key(owner, vc_id));
     e.storage().persistent().remove(&DataKey::VaultVC(owner.clone(), vc_id.clone()));
 }
 
+/// Reads a `VaultVC` entry in either layout it was written with.
+///
+/// A struct only decodes from a map with exactly its fields, so the field count decides
+/// between the baseline layout and the one with a credential type.
+pub fn read_string_vault_vc(
+    e: &Env,
+    owner: &Address,
+    vc_id: &String,
+) -> Option<StringVerifiableCredential> {
+    let raw: Val = e
+        .storage()
+        .persistent()
+        .get(&DataKey::VaultVC(owner.clone(), vc_id.clone()))?;
+    let fields: Map<Symbol, Val> = raw.into_val(e);
+    if fields.len() == BASELINE_VC_FIELDS {
+        let vc: BaselineVerifiableCredential = raw.into_val(e);
+        Some(verifiable_credential::from_baseline_layout(e, vc))
+    } else {
+        Some(raw.into_val(e))
+    }
+}
+
+pub fn write_string_vault_vc(e: &Env, owner: &Address, vc: &StringVerifiableCredential) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VaultVC(owner.clone(), vc.id.clone()), vc)
+}
+
+fn read_vault_vc_chunks(e: &Env, owner: &Address, vc_id: &String) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Credential(CredentialKey::Chunks(owner.clone(), vc_id.clone())))
+        .unwrap_or(0)
+}
+
+fn record_key(owner: &Address, vc_id: &String) -> DataKey {
+    DataKey::Credential(CredentialKey::Record(owner.clone(), vc_id.clone()))
+}
+
+fn chunk_key(owner: &Address, vc_id: &String, index: u32) -> DataKey {
+    DataKey::Credential(CredentialKey::Chunk(owner.clone(), vc_id.clone(), index))
+}
+
+pub fn read_vault_bytes_layout(e: &Env, owner: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Credential(CredentialKey::BytesLayout(owner.clone())))
+        .unwrap_or(false)
+}
+
+pub fn write_vault_bytes_layout(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Credential(CredentialKey::BytesLayout(owner.clone())), &true)
+}
+
+pub fn remove_vault_bytes_layout(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Credential(CredentialKey::BytesLayout(owner.clone())));
+}
+
+pub fn read_vc_upload(e: &Env, owner: &Address, vc_id: &String) -> Option<PayloadUpload> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Credential(CredentialKey::Upload(owner.clone(), vc_id.clone())))
+}
+
+pub fn write_vc_upload(e: &Env, owner: &Address, vc_id: &String, upload: &PayloadUpload) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Credential(CredentialKey::Upload(owner.clone(), vc_id.clone())), upload)
+}
+
+pub fn remove_vc_upload(e: &Env, owner: &Address, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Credential(CredentialKey::Upload(owner.clone(), vc_id.clone())));
+}
+
+pub fn read_vc_upload_part(e: &Env, owner: &Address, vc_id: &String, index: u32) -> Option<Bytes> {
+    e.storage()
+        .persistent()
+        .get(&upload_part_key(owner, vc_id, index))
+}
+
+pub fn write_vc_upload_part(e: &Env, owner: &Address, vc_id: &String, index: u32, part: &Bytes) {
+    e.storage()
+        .persistent()
+        .set(&upload_part_key(owner, vc_id, index), part)
+}
+
+pub fn remove_vc_upload_part(e: &Env, owner: &Address, vc_id: &String, index: u32) {
+    e.storage()
+        .persistent()
+        .remove(&upload_part_key(owner, vc_id, index));
+}
+
+pub fn read_vc_uploads(e: &Env, owner: &Address) -> Vec<String> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Credential(CredentialKey::Uploads(owner.clone())))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+pub fn write_vc_uploads(e: &Env, owner: &Address, vc_ids: &Vec<String>) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Credential(CredentialKey::Uploads(owner.clone())), vc_ids)
+}
+
+pub fn remove_vc_uploads(e: &Env, owner: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Credential(CredentialKey::Uploads(owner.clone())));
+}
+
+pub fn read_vc_shares(e: &Env, owner: &Address, vc_id: &String) -> Map<Address, u64> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Credential(CredentialKey::Shares(owner.clone(), vc_id.clone())))
+        .unwrap_or_else(|| Map::new(e))
+}
+
+pub fn write_vc_shares(e: &Env, owner: &Address, vc_id: &String, shares: &Map<Address, u64>) {
+    let key = DataKey::Credential(CredentialKey::Shares(owner.clone(), vc_id.clone()));
+    if shares.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, shares);
+    }
+}
+
+pub fn remove_vc_shares(e: &Env, owner: &Address, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Credential(CredentialKey::Shares(owner.clone(), vc_id.clone())));
+}
+
+pub fn read_verification_receipt(
+    e: &Env,
+    verifier: &Address,
+    vc_id: &String,
+) -> Option<VerificationReceipt> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Credential(CredentialKey::Receipt(verifier.clone(), vc_id.clone())))
+}
+
+pub fn write_verification_receipt(
+    e: &Env,
+    verifier: &Address,
+    vc_id: &String,
+    receipt: &VerificationReceipt,
+) {
+    e.storage().persistent().set(
+        &DataKey::Credential(CredentialKey::Receipt(verifier.clone(), vc_id.clone())),
+        receipt,
+    )
+}
+
+fn upload_part_key(owner: &Address, vc_id: &String, index: u32) -> DataKey {
+    DataKey::Credential(CredentialKey::UploadPart(owner.clone(), vc_id.clone(), index))
+}
+
 pub fn read_vault_vc_ids(e: &Env, owner: &Address) -> Vec<String> {
     match e.storage().persistent().get(&DataKey::VaultVCIds(owner.clone())) {
         Some(v) => v,
@@ -192,6 +993,10 @@ pub fn write_vault_vc_ids(e: &Env, owner: &Address, ids: &Vec<String>) {
     e.storage().persistent().set(&DataKey::VaultVCIds(owner.clone()), ids)
 }
 
+pub fn remove_vault_vc_ids(e: &Env, owner: &Address) {
+    e.storage().persistent().remove(&DataKey::VaultVCIds(owner.clone()));
+}
+
 pub fn append_vault_vc_id(e: &Env, owner: &Address, vc_id: &String) {
     let mut ids = read_vault_vc_ids(e, owner);
     if !ids.contains(vc_id.clone()) {
@@ -223,6 +1028,10 @@ pub fn read_vc_status(e: &Env, vc_id: &String) -> VCStatus {
         .unwrap_or(VCStatus::Invalid)
 }
 
+pub fn has_vc_status(e: &Env, vc_id: &String) -> bool {
+    e.storage().persistent().has(&DataKey::VCStatus(vc_id.clone()))
+}
+
 pub fn write_vc_owner(e: &Env, vc_id: &String, owner: &Address) {
     e.storage().persistent().set(&DataKey::VCOwner(vc_id.clone()), owner)
 }
@@ -231,6 +1040,167 @@ pub fn read_vc_owner(e: &Env, vc_id: &String) -> Option<Address> {
     e.storage().persistent().get(&DataKey::VCOwner(vc_id.clone()))
 }
 
+pub fn write_vc_issuer(e: &Env, vc_id: &String, issuer: &Address) {
+    e.storage().persistent().set(&DataKey::VCIssuer(vc_id.clone()), issuer)
+}
+
+pub fn read_vc_issuer(e: &Env, vc_id: &String) -> Option<Address> {
+    e.storage().persistent().get(&DataKey::VCIssuer(vc_id.clone()))
+}
+
+pub fn write_vc_schema(e: &Env, vc_id: &String, schema: &SchemaRef) {
+    e.storage().persistent().set(&DataKey::VCSchema(vc_id.clone()), schema)
+}
+
+pub fn read_vc_schema(e: &Env, vc_id: &String) -> Option<SchemaRef> {
+    e.storage().persistent().get(&DataKey::VCSchema(vc_id.clone()))
+}
+
+pub fn remove_vc_schema(e: &Env, vc_id: &String) {
+    e.storage().persistent().remove(&DataKey::VCSchema(vc_id.clone()))
+}
+
+pub fn write_vc_payload_hash(e: &Env, vc_id: &String, hash: &BytesN<32>) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VCPayloadHash(vc_id.clone()), hash)
+}
+
+pub fn read_vc_payload_hash(e: &Env, vc_id: &String) -> Option<BytesN<32>> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VCPayloadHash(vc_id.clone()))
+}
+
+pub fn remove_vc_payload_hash(e: &Env, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VCPayloadHash(vc_id.clone()))
+}
+
+pub fn write_vc_commitment(e: &Env, vc_id: &String, commitment: &BytesN<32>) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::VCCommitment(vc_id.clone()), commitment)
+}
+
+pub fn read_vc_commitment(e: &Env, vc_id: &String) -> Option<BytesN<32>> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::VCCommitment(vc_id.clone()))
+}
+
+pub fn remove_vc_commitment(e: &Env, vc_id: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::VCCommitment(vc_id.clone()))
+}
+
+// -----------------
+// Schema registry
+// -----------------
+
+pub fn read_schema(e: &Env, id: &String, version: u32) -> Option<Schema> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Schema(id.clone(), version))
+}
+
+pub fn write_schema(e: &Env, schema: &Schema) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Schema(schema.id.clone(), schema.version), schema)
+}
+
+pub fn read_schema_latest(e: &Env, id: &String) -> Option<u32> {
+    e.storage().persistent().get(&DataKey::SchemaLatest(id.clone()))
+}
+
+pub fn write_schema_latest(e: &Env, id: &String, version: &u32) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::SchemaLatest(id.clone()), version)
+}
+
+// -----------------
+// DID registry (persistent)
+// -----------------
+
+pub fn has_did_document(e: &Env, did: &String) -> bool {
+    e.storage().persistent().has(&DataKey::DidDocument(did.clone()))
+}
+
+pub fn read_did_document(e: &Env, did: &String) -> Option<DidDocument> {
+    e.storage().persistent().get(&DataKey::DidDocument(did.clone()))
+}
+
+pub fn write_did_document(e: &Env, did: &String, doc: &DidDocument) {
+    e.storage().persistent().set(&DataKey::DidDocument(did.clone()), doc)
+}
+
+pub fn read_did_attestation(e: &Env, did: &String) -> Option<Address> {
+    e.storage().persistent().get(&DataKey::DidAttestation(did.clone()))
+}
+
+pub fn write_did_attestation(e: &Env, did: &String, controller: &Address) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::DidAttestation(did.clone()), controller)
+}
+
+pub fn remove_did_attestation(e: &Env, did: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::DidAttestation(did.clone()));
+}
+
+// -----------------
+// Issuer DID bindings (persistent)
+// -----------------
+
+pub fn read_issuer_did_binding(
+    e: &Env,
+    issuer: &Address,
+    did: &String,
+) -> Option<IssuerDidBinding> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::IssuerDid(issuer.clone(), did.clone()))
+}
+
+pub fn write_issuer_did_binding(
+    e: &Env,
+    issuer: &Address,
+    did: &String,
+    binding: &IssuerDidBinding,
+) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::IssuerDid(issuer.clone(), did.clone()), binding)
+}
+
+pub fn remove_issuer_did_binding(e: &Env, issuer: &Address, did: &String) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::IssuerDid(issuer.clone(), did.clone()));
+}
+
+// -----------------
+// Trusted issuer registry (persistent)
+// -----------------
+
+pub fn read_accreditation(e: &Env, subject: &Address) -> Option<Accreditation> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Accreditation(subject.clone()))
+}
+
+pub fn write_accreditation(e: &Env, subject: &Address, accreditation: &Accreditation) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Accreditation(subject.clone()), accreditation)
+}
+
 // -----------------
 // Legacy migrations
 // -----------------
@@ -239,22 +1209,75 @@ pub fn read_legacy_issuance_vcs(e: &Env) -> Option<Vec<String>> {
     e.storage().persistent().get(&DataKey::LegacyIssuanceVCs)
 }
 
+pub fn write_legacy_issuance_vcs(e: &Env, vcs: &Vec<String>) {
+    e.storage().persistent().set(&DataKey::LegacyIssuanceVCs, vcs)
+}
+
 pub fn remove_legacy_issuance_vcs(e: &Env) {
     e.storage().persistent().remove(&DataKey::LegacyIssuanceVCs);
 }
 
+/// Legacy deployments without any revocation never wrote the map: treat it as empty.
 pub fn read_legacy_issuance_revocations(e: &Env) -> Map<String, LegacyRevocation> {
-    e.storage().persistent().get(&DataKey::LegacyIssuanceRevocations).unwrap()
+    e.storage()
+        .persistent()
+        .get(&DataKey::LegacyIssuanceRevocations)
+        .unwrap_or_else(|| Map::new(e))
+}
+
+pub fn write_legacy_issuance_revocations(e: &Env, revocations: &Map<String, LegacyRevocation>) {
+    e.storage().persistent().set(&DataKey::LegacyIssuanceRevocations, revocations)
 }
 
 pub fn remove_legacy_issuance_revocations(e: &Env) {
     e.storage().persistent().remove(&DataKey::LegacyIssuanceRevocations);
 }
 
-pub fn read_legacy_vault_vcs(e: &Env, owner: &Address) -> Option<Vec<VerifiableCredential>> {
+pub fn read_legacy_vault_vcs(
+    e: &Env,
+    owner: &Address,
+) -> Option<Vec<LegacyVerifiableCredential>> {
     e.storage().persistent().get(&DataKey::LegacyVaultVCs(owner.clone()))
 }
 
+pub fn write_legacy_vault_vcs(
+    e: &Env,
+    owner: &Address,
+    vcs: &Vec<LegacyVerifiableCredential>,
+) {
+    e.storage().persistent().set(&DataKey::LegacyVaultVCs(owner.clone()), vcs)
+}
+
 pub fn remove_legacy_vault_vcs(e: &Env, owner: &Address) {
     e.storage().persistent().remove(&DataKey::LegacyVaultVCs(owner.clone()));
 }
+
+pub fn read_legacy_issuance_cursor(e: &Env) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::LegacyIssuanceCursor)
+        .unwrap_or_default()
+}
+
+pub fn write_legacy_issuance_cursor(e: &Env, cursor: &u32) {
+    e.storage().persistent().set(&DataKey::LegacyIssuanceCursor, cursor)
+}
+
+pub fn remove_legacy_issuance_cursor(e: &Env) {
+    e.storage().persistent().remove(&DataKey::LegacyIssuanceCursor);
+}
+
+pub fn read_legacy_vault_cursor(e: &Env, owner: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::LegacyVaultCursor(owner.clone()))
+        .unwrap_or_default()
+}
+
+pub fn write_legacy_vault_cursor(e: &Env, owner: &Address, cursor: &u32) {
+    e.storage().persistent().set(&DataKey::LegacyVaultCursor(owner.clone()), cursor)
+}
+
+pub fn remove_legacy_vault_cursor(e: &Env, owner: &Address) {
+    e.storage().persistent().remove(&DataKey::LegacyVaultCursor(owner.clone()));
+}
@@ -1,5 +1,29 @@
+extern crate std;
+
 use crate::contract::{ActaContract, ActaContractClient};
-use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+use crate::did_registry::{Service, VerificationMethod, MAX_VERIFICATION_METHODS};
+use crate::error::ContractError;
+use crate::fee::{FeeOperation, FeeShare, FeeTier, IssuerFee, RefundPolicy, MAX_REFUND_WINDOW};
+use crate::issuer::{IssuerTerms, MAX_VAULT_ISSUERS};
+use crate::issuer_did::BindingProof;
+use crate::issuer_request::MAX_REQUEST_HORIZON;
+use crate::migration::MIGRATION_BATCH_SIZE;
+use crate::recovery::RECOVERY_APPROVAL_WINDOW;
+use crate::schema::{SchemaRef, SchemaStatus};
+use crate::storage::{self, DataKey, LegacyRevocation};
+use crate::validation::{ValidationLimits, DEFAULT_LIMITS, MAX_FEE_AMOUNT};
+use crate::vault::{CLOSE_BATCH_SIZE, ROTATION_BATCH_SIZE};
+use crate::vault_admins::AdminAction;
+use crate::vc_status::VCStatus;
+use crate::verifiable_credential::{
+    BaselineVerifiableCredential, LegacyVerifiableCredential, StringVerifiableCredential,
+    MAX_PENDING_UPLOADS, PAYLOAD_CHUNK_SIZE, UPLOAD_TTL,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Bytes, BytesN, Env, IntoVal, InvokeError, Map, String, Vec,
+};
 
 fn setup() -> (Env, Address, Address, Address, ActaContractClient<'static>) {
     let env = Env::default();
@@ -11,6 +35,59 @@ fn setup() -> (Env, Address, Address, Address, ActaContractClient<'static>) {
     (env, admin, issuer, contract_id, client)
 }
 
+fn assert_contract_error<T>(
+    res: Result<T, Result<soroban_sdk::Error, InvokeError>>,
+    expected: ContractError,
+) {
+    match res {
+        Err(Ok(err)) => assert_eq!(err, soroban_sdk::Error::from(expected)),
+        Err(Err(_)) => panic!("expected {:?}, got a non-contract error", expected),
+        Ok(_) => panic!("expected {:?}, call succeeded", expected),
+    }
+}
+
+/// Initializes the contract and creates a vault with `issuer` authorized and
+/// bound (by admin attestation) to `did:pkh:stellar:testnet:ISSUER`.
+fn setup_vault(
+    env: &Env,
+    client: &ActaContractClient,
+    admin: &Address,
+    issuer: &Address,
+) -> Address {
+    client.initialize(admin, &String::from_str(env, "did:acta:default"));
+    let owner = Address::generate(env);
+    client.create_vault(&owner, &String::from_str(env, "did:pkh:stellar:testnet:OWNER"));
+    client.authorize_issuer(&owner, issuer);
+    client.attest_issuer_did(
+        admin,
+        issuer,
+        &String::from_str(env, "did:pkh:stellar:testnet:ISSUER"),
+    );
+    owner
+}
+
+fn issue_vc(
+    env: &Env,
+    client: &ActaContractClient,
+    owner: &Address,
+    issuer: &Address,
+    vc_id: &str,
+) -> String {
+    let vc_id = String::from_str(env, vc_id);
+    client.issue(
+        owner,
+        &vc_id,
+        &Bytes::from_slice(env, b"<ciphertext>"),
+        &client.address,
+        issuer,
+        &String::from_str(env, "did:pkh:stellar:testnet:ISSUER"),
+        &String::from_str(env, "EducationCredential"),
+        &None,
+        &None,
+    );
+    vc_id
+}
+
 #[test]
 fn test_initialize_and_create_vault() {
     let (env, admin, _issuer, _contract_id, client) = setup();
@@ -35,8 +112,9 @@ fn test_vault_authorize_and_store_and_list_and_get() {
     client.authorize_issuer(&owner, &issuer);
 
     let vc_id = String::from_str(&env, "vc-1");
-    let vc_data = String::from_str(&env, "<ciphertext>");
+    let vc_data = Bytes::from_slice(&env, b"<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.attest_issuer_did(&admin, &issuer, &issuer_did);
 
     // Issue (stores payload + status)
     client.issue(
@@ -46,6 +124,9 @@ fn test_vault_authorize_and_store_and_list_and_get() {
         &contract_id,
         &issuer,
         &issuer_did,
+        &String::from_str(&env, "EducationCredential"),
+        &None,
+        &None,
     );
 
     let ids = client.list_vc_ids(&owner);
@@ -66,8 +147,9 @@ fn test_issue_verify_revoke_flow_local_vault() {
     client.authorize_issuer(&owner, &issuer);
 
     let vc_id = String::from_str(&env, "vc-123");
-    let vc_data = String::from_str(&env, "<ciphertext>");
+    let vc_data = Bytes::from_slice(&env, b"<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.attest_issuer_did(&admin, &issuer, &issuer_did);
 
     client.issue(
         &owner,
@@ -76,6 +158,9 @@ fn test_issue_verify_revoke_flow_local_vault() {
         &contract_id,
         &issuer,
         &issuer_did,
+        &String::from_str(&env, "EducationCredential"),
+        &None,
+        &None,
     );
 
     let m = client.verify_vc(&owner, &vc_id);
@@ -104,8 +189,9 @@ fn test_push_moves_between_vaults() {
     client.authorize_issuer(&from_owner, &issuer);
 
     let vc_id = String::from_str(&env, "vc-push");
-    let vc_data = String::from_str(&env, "<ciphertext>");
+    let vc_data = Bytes::from_slice(&env, b"<ciphertext>");
     let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.attest_issuer_did(&admin, &issuer, &issuer_did);
 
     client.issue(
         &from_owner,
@@ -114,6 +200,9 @@ fn test_push_moves_between_vaults() {
         &contract_id,
         &issuer,
         &issuer_did,
+        &String::from_str(&env, "EducationCredential"),
+        &None,
+        &None,
     );
 
     client.push(&from_owner, &to_owner, &vc_id, &issuer);
@@ -131,7 +220,2674 @@ fn test_authorize_issuers_bulk() {
     client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
 
     let issuer2 = Address::generate(&env);
-    let issuers = vec![&env, issuer.clone(), issuer2.clone()];
+    let issuers = vec![&env, issuer.clone(), issuer2.clone(), issuer.clone()];
 
     client.authorize_issuers(&owner, &issuers);
+    // Merges instead of overwriting.
+    client.authorize_issuers(&owner, &vec![&env, Address::generate(&env)]);
+    client.get_issuer_allowance(&owner, &issuer);
+    client.get_issuer_allowance(&owner, &issuer2);
+}
+
+#[test]
+fn test_update_issuers_reports_changes() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    let issuer2 = Address::generate(&env);
+    let unknown = Address::generate(&env);
+    let update = client.update_issuers(
+        &owner,
+        &vec![&env, issuer.clone(), issuer2.clone(), issuer2.clone()],
+        &vec![&env, unknown.clone()],
+    );
+    assert_eq!(update.added, vec![&env, issuer2.clone()]);
+    assert_eq!(update.removed, Vec::new(&env));
+
+    let update = client.update_issuers(&owner, &Vec::new(&env), &vec![&env, issuer.clone()]);
+    assert_eq!(update.removed, vec![&env, issuer.clone()]);
+    assert_contract_error(
+        client.try_get_issuer_allowance(&owner, &issuer),
+        ContractError::IssuerNotAuthorized,
+    );
+
+    assert_contract_error(
+        client.try_update_issuers(&owner, &vec![&env, unknown.clone()], &vec![&env, unknown]),
+        ContractError::InvalidArgument,
+    );
+
+    let mut many = Vec::new(&env);
+    for _ in 0..MAX_VAULT_ISSUERS {
+        many.push_back(Address::generate(&env));
+    }
+    env.budget().reset_default();
+    assert_contract_error(
+        client.try_update_issuers(&owner, &many, &Vec::new(&env)),
+        ContractError::LimitExceeded,
+    );
+}
+
+#[test]
+fn test_legacy_issuer_list_is_read_as_set() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    let owner = Address::generate(&env);
+    client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
+
+    env.as_contract(&contract_id, || {
+        let key = storage::DataKey::VaultIssuerSet(owner.clone());
+        env.storage().persistent().remove(&key);
+        let legacy = storage::DataKey::VaultIssuers(owner.clone());
+        env.storage().persistent().set(&legacy, &vec![&env, issuer.clone()]);
+    });
+
+    client.get_issuer_allowance(&owner, &issuer);
+    let update = client.update_issuers(&owner, &vec![&env, issuer.clone()], &Vec::new(&env));
+    assert_eq!(update.added, Vec::new(&env));
+}
+
+fn vc_id_at(env: &Env, i: u32) -> String {
+    String::from_str(env, &std::format!("legacy-{i}"))
+}
+
+#[test]
+fn test_migrate_vault_in_batches() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    let owner = Address::generate(&env);
+    client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
+
+    let total: u32 = 240;
+    env.as_contract(&contract_id, || {
+        let mut vcs = Vec::new(&env);
+        for i in 0..total {
+            vcs.push_back(LegacyVerifiableCredential {
+                id: vc_id_at(&env, i),
+                data: String::from_str(&env, "<ciphertext>"),
+                issuance_contract: contract_id.clone(),
+                issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+            });
+        }
+        storage::write_legacy_vault_vcs(&env, &owner, &vcs);
+    });
+
+    let progress = client.migration_progress(&Some(owner.clone()));
+    assert_eq!(progress.migrated, 0);
+    assert_eq!(progress.remaining, total);
+    assert!(!progress.done);
+
+    let mut calls = 0;
+    loop {
+        // Every batch must fit in a single transaction's default budget.
+        env.budget().reset_default();
+        let progress = client.migrate(&Some(owner.clone()));
+        calls += 1;
+        assert_eq!(progress.migrated + progress.remaining, total);
+        assert_eq!(client.list_vc_ids(&owner).len(), progress.migrated);
+        if progress.done {
+            break;
+        }
+        // Each batch is popped off the legacy list; the rest is kept until the final batch.
+        env.as_contract(&contract_id, || {
+            let pending = storage::read_legacy_vault_vcs(&env, &owner).unwrap();
+            assert_eq!(pending.len(), progress.remaining);
+        });
+    }
+    assert_eq!(calls, total.div_ceil(MIGRATION_BATCH_SIZE));
+
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_legacy_vault_vcs(&env, &owner).is_none());
+    });
+    assert!(client.get_vc(&owner, &vc_id_at(&env, 0)).is_some());
+    assert!(client.get_vc(&owner, &vc_id_at(&env, total - 1)).is_some());
+    assert!(client.migration_progress(&Some(owner.clone())).done);
+
+    // IDs keep their legacy order.
+    let ids = client.list_vc_ids(&owner);
+    assert_eq!(ids.get_unchecked(0), vc_id_at(&env, 0));
+    assert_eq!(ids.get_unchecked(total - 1), vc_id_at(&env, total - 1));
+
+    assert_contract_error(
+        client.try_migrate(&Some(owner.clone())),
+        ContractError::VCSAlreadyMigrated,
+    );
+}
+
+#[test]
+fn test_migrate_issuance_registry_in_batches() {
+    let (env, admin, _issuer, contract_id, client) = setup();
+
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    let total: u32 = 300;
+    env.as_contract(&contract_id, || {
+        let mut vcs = Vec::new(&env);
+        let mut revocations = Map::new(&env);
+        for i in 0..total {
+            let vc_id = vc_id_at(&env, i);
+            vcs.push_back(vc_id.clone());
+            if i % 3 == 0 {
+                revocations.set(
+                    vc_id.clone(),
+                    LegacyRevocation {
+                        vc_id,
+                        date: String::from_str(&env, "2024-01-01T00:00:00Z"),
+                    },
+                );
+            }
+        }
+        storage::write_legacy_issuance_vcs(&env, &vcs);
+        storage::write_legacy_issuance_revocations(&env, &revocations);
+    });
+
+    // First batch only: the rest stays pending.
+    env.budget().reset_default();
+    let progress = client.migrate(&None);
+    assert_eq!(progress.migrated, MIGRATION_BATCH_SIZE);
+    assert_eq!(client.migration_progress(&None), progress);
+    env.as_contract(&contract_id, || {
+        let pending = storage::read_legacy_issuance_vcs(&env).unwrap();
+        assert_eq!(pending.len(), total - MIGRATION_BATCH_SIZE);
+        // Migrated revocations are dropped from the legacy map.
+        let revocations = storage::read_legacy_issuance_revocations(&env);
+        assert!(!revocations.contains_key(vc_id_at(&env, total - 3)));
+        assert!(revocations.contains_key(vc_id_at(&env, 0)));
+    });
+
+    loop {
+        env.budget().reset_default();
+        if client.migrate(&None).done {
+            break;
+        }
+    }
+
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_legacy_issuance_vcs(&env).is_none());
+        assert_eq!(
+            storage::read_vc_status(&env, &vc_id_at(&env, 3)),
+            crate::vc_status::VCStatus::Revoked(String::from_str(&env, "2024-01-01T00:00:00Z"))
+        );
+        assert_eq!(
+            storage::read_vc_status(&env, &vc_id_at(&env, total - 1)),
+            crate::vc_status::VCStatus::Valid
+        );
+    });
+
+    assert_contract_error(client.try_migrate(&None), ContractError::VCSAlreadyMigrated);
+}
+
+#[test]
+fn test_error_already_initialized() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    assert_contract_error(
+        client.try_initialize(&admin, &String::from_str(&env, "did:acta:default")),
+        ContractError::AlreadyInitialized,
+    );
+    assert_contract_error(
+        client.try_create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER")),
+        ContractError::AlreadyInitialized,
+    );
+}
+
+#[test]
+fn test_error_issuer_not_authorized() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let stranger = Address::generate(&env);
+
+    assert_contract_error(
+        client.try_issue(
+            &owner,
+            &String::from_str(&env, "vc-1"),
+            &Bytes::from_slice(&env, b"<ciphertext>"),
+            &contract_id,
+            &stranger,
+            &String::from_str(&env, "did:pkh:stellar:testnet:STRANGER"),
+            &String::from_str(&env, "EducationCredential"),
+            &None,
+            &None,
+        ),
+        ContractError::IssuerNotAuthorized,
+    );
+    assert_contract_error(
+        client.try_revoke_issuer(&owner, &stranger),
+        ContractError::IssuerNotAuthorized,
+    );
+}
+
+#[test]
+fn test_error_issuer_already_authorized() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    assert_contract_error(
+        client.try_authorize_issuer(&owner, &issuer),
+        ContractError::IssuerAlreadyAuthorized,
+    );
+}
+
+#[test]
+fn test_error_vault_revoked() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    client.revoke_vault(&owner);
+    assert_contract_error(
+        client.try_authorize_issuer(&owner, &Address::generate(&env)),
+        ContractError::VaultRevoked,
+    );
+}
+
+#[test]
+fn test_error_vc_not_found() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let other = Address::generate(&env);
+    client.create_vault(&other, &String::from_str(&env, "did:pkh:stellar:testnet:OTHER"));
+
+    let missing = String::from_str(&env, "missing");
+    assert_contract_error(
+        client.try_revoke(&missing, &String::from_str(&env, "2025-12-18T00:00:00Z")),
+        ContractError::VCNotFound,
+    );
+    assert_contract_error(
+        client.try_push(&owner, &other, &missing, &issuer),
+        ContractError::VCNotFound,
+    );
+}
+
+#[test]
+fn test_error_vc_already_revoked() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let vc_id = issue_vc(&env, &client, &owner, &issuer, "vc-1");
+
+    let date = String::from_str(&env, "2025-12-18T00:00:00Z");
+    client.revoke(&vc_id, &date);
+    assert_contract_error(client.try_revoke(&vc_id, &date), ContractError::VCAlreadyRevoked);
+}
+
+#[test]
+fn test_error_vault_not_initialized() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    let owner = Address::generate(&env);
+
+    assert_contract_error(
+        client.try_authorize_issuer(&owner, &issuer),
+        ContractError::VaultNotInitialized,
+    );
+    assert_contract_error(client.try_revoke_vault(&owner), ContractError::VaultNotInitialized);
+}
+
+#[test]
+fn test_error_not_initialized() {
+    let (env, _admin, _issuer, _contract_id, client) = setup();
+
+    assert_contract_error(client.try_set_fee_enabled(&false), ContractError::NotInitialized);
+    assert_contract_error(
+        client.try_set_contract_admin(&Address::generate(&env)),
+        ContractError::NotInitialized,
+    );
+}
+
+#[test]
+fn test_error_invalid_vault_contract() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    assert_contract_error(
+        client.try_issue(
+            &owner,
+            &String::from_str(&env, "vc-1"),
+            &Bytes::from_slice(&env, b"<ciphertext>"),
+            &Address::generate(&env),
+            &issuer,
+            &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+            &String::from_str(&env, "EducationCredential"),
+            &None,
+            &None,
+        ),
+        ContractError::InvalidVaultContract,
+    );
+}
+
+#[test]
+fn test_error_fee_not_configured() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    assert_contract_error(client.try_set_fee_enabled(&true), ContractError::FeeNotConfigured);
+
+    // Disabling never requires a config.
+    client.set_fee_enabled(&false);
+}
+
+#[test]
+fn test_fee_charged_on_issue() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    token::StellarAssetClient::new(&env, &token).mint(&issuer, &100);
+
+    let fee_dest = Address::generate(&env);
+    client.set_fee_config(&token, &fee_dest, &30);
+    client.set_fee_enabled(&true);
+
+    issue_vc(&env, &client, &owner, &issuer, "vc-1");
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&issuer), 70);
+    assert_eq!(token_client.balance(&fee_dest), 30);
+}
+
+#[test]
+fn test_error_fee_transfer_failed() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    // Issuer holds no balance in the fee token.
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.set_fee_config(&token, &Address::generate(&env), &30);
+    client.set_fee_enabled(&true);
+
+    assert_contract_error(
+        client.try_issue(
+            &owner,
+            &String::from_str(&env, "vc-1"),
+            &Bytes::from_slice(&env, b"<ciphertext>"),
+            &contract_id,
+            &issuer,
+            &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+            &String::from_str(&env, "EducationCredential"),
+            &None,
+            &None,
+        ),
+        ContractError::FeeTransferFailed,
+    );
+}
+
+#[test]
+fn test_error_issuance_contract_call_failed() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    // A legacy VC pointing to an issuance contract that does not exist.
+    let vc_id = String::from_str(&env, "vc-foreign");
+    env.as_contract(&contract_id, || {
+        let vcs = vec![
+            &env,
+            LegacyVerifiableCredential {
+                id: vc_id.clone(),
+                data: String::from_str(&env, "<ciphertext>"),
+                issuance_contract: Address::generate(&env),
+                issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+            },
+        ];
+        storage::write_legacy_vault_vcs(&env, &owner, &vcs);
+    });
+    client.migrate(&Some(owner.clone()));
+
+    assert_contract_error(
+        client.try_verify_vc(&owner, &vc_id),
+        ContractError::IssuanceContractCallFailed,
+    );
+}
+
+fn try_issue_with(
+    env: &Env,
+    client: &ActaContractClient,
+    owner: &Address,
+    issuer: &Address,
+    vc_id: &String,
+    vc_data: &Bytes,
+    issuer_did: &str,
+) -> Result<
+    Result<String, soroban_sdk::ConversionError>,
+    Result<soroban_sdk::Error, InvokeError>,
+> {
+    client.try_issue(
+        owner,
+        vc_id,
+        vc_data,
+        &client.address,
+        issuer,
+        &String::from_str(env, issuer_did),
+        &String::from_str(env, "EducationCredential"),
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_error_invalid_id() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let data = Bytes::from_slice(&env, b"<ciphertext>");
+    let did = "did:pkh:stellar:testnet:ISSUER";
+
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &String::from_str(&env, ""), &data, did),
+        ContractError::InvalidId,
+    );
+
+    let long_id = String::from_bytes(&env, &[b'x'; (DEFAULT_LIMITS.max_id_len + 1) as usize]);
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &long_id, &data, did),
+        ContractError::InvalidId,
+    );
+
+    let other = Address::generate(&env);
+    client.create_vault(&other, &String::from_str(&env, "did:pkh:stellar:testnet:OTHER"));
+    assert_contract_error(
+        client.try_push(&owner, &other, &long_id, &issuer),
+        ContractError::InvalidId,
+    );
+}
+
+#[test]
+fn test_error_payload_too_large() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let vc_id = String::from_str(&env, "vc-1");
+    let did = "did:pkh:stellar:testnet:ISSUER";
+
+    client.set_validation_limits(&ValidationLimits {
+        max_payload_len: 8,
+        ..DEFAULT_LIMITS
+    });
+
+    let too_large = Bytes::from_slice(&env, b"123456789");
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &vc_id, &too_large, did),
+        ContractError::PayloadTooLarge,
+    );
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &vc_id, &Bytes::new(&env), did),
+        ContractError::PayloadTooLarge,
+    );
+
+    // Exactly at the limit is accepted.
+    let at_limit = Bytes::from_slice(&env, b"12345678");
+    try_issue_with(&env, &client, &owner, &issuer, &vc_id, &at_limit, did)
+        .unwrap()
+        .unwrap();
+}
+
+#[test]
+fn test_error_invalid_did() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let vc_id = String::from_str(&env, "vc-1");
+    let data = Bytes::from_slice(&env, b"<ciphertext>");
+
+    for did in [
+        "",
+        "university.edu",
+        "did:",
+        "did:web",
+        "did:web:",
+        "did::example",
+        "did:Web:university.edu",
+        "DID:web:university.edu",
+    ] {
+        assert_contract_error(
+            try_issue_with(&env, &client, &owner, &issuer, &vc_id, &data, did),
+            ContractError::InvalidDid,
+        );
+    }
+
+    assert_contract_error(
+        client.try_create_vault(&Address::generate(&env), &String::from_str(&env, "not-a-did")),
+        ContractError::InvalidDid,
+    );
+
+    let long_did = std::format!("did:web:{}", "a".repeat(DEFAULT_LIMITS.max_did_len as usize));
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &vc_id, &data, &long_did),
+        ContractError::InvalidDid,
+    );
+
+    client.attest_issuer_did(&admin, &issuer, &String::from_str(&env, "did:web:university.edu"));
+    try_issue_with(&env, &client, &owner, &issuer, &vc_id, &data, "did:web:university.edu")
+        .unwrap()
+        .unwrap();
+}
+
+#[test]
+fn test_error_invalid_fee_amount() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    assert_contract_error(
+        client.try_set_fee_config(&Address::generate(&env), &Address::generate(&env), &-1),
+        ContractError::InvalidFeeAmount,
+    );
+    client.set_fee_config(&Address::generate(&env), &Address::generate(&env), &0);
+
+    // Amounts large enough to overflow a basis-point split are rejected up front.
+    assert_contract_error(
+        client.try_set_fee_config(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &(MAX_FEE_AMOUNT + 1),
+        ),
+        ContractError::InvalidFeeAmount,
+    );
+    let issuer = Address::generate(&env);
+    client.set_fee_config(&Address::generate(&env), &Address::generate(&env), &MAX_FEE_AMOUNT);
+    client.set_fee_enabled(&true);
+    client.set_fee_tiers(&admin, &vec![&env, FeeTier { min_issuances: 0, discount_bps: 2_500 }]);
+    let quote = client.quote_fee(&issuer, &String::from_str(&env, "EducationCredential"));
+    assert_eq!(quote.amount, MAX_FEE_AMOUNT * 7_500 / 10_000);
+}
+
+#[test]
+fn test_error_invalid_validation_limits() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    assert_eq!(client.get_validation_limits(), DEFAULT_LIMITS);
+
+    for limits in [
+        ValidationLimits { max_id_len: 0, ..DEFAULT_LIMITS },
+        ValidationLimits { max_payload_len: 0, ..DEFAULT_LIMITS },
+        ValidationLimits { max_did_len: 3, ..DEFAULT_LIMITS },
+        ValidationLimits { max_did_len: crate::validation::DID_LEN_CAP + 1, ..DEFAULT_LIMITS },
+    ] {
+        assert_contract_error(
+            client.try_set_validation_limits(&limits),
+            ContractError::InvalidValidationLimits,
+        );
+    }
+
+    let limits = ValidationLimits {
+        max_id_len: 64,
+        max_did_len: 128,
+        max_payload_len: 1024,
+    };
+    client.set_validation_limits(&limits);
+    assert_eq!(client.get_validation_limits(), limits);
+}
+
+fn key(env: &Env, id: &str, byte: u8) -> VerificationMethod {
+    VerificationMethod {
+        id: String::from_str(env, id),
+        public_key: BytesN::from_array(env, &[byte; 32]),
+    }
+}
+
+/// `did:pkh:stellar:testnet:<address>` for a test address.
+fn pkh_did_str(address: &Address) -> std::string::String {
+    let strkey = address.to_string();
+    let mut buf = std::vec![0u8; strkey.len() as usize];
+    strkey.copy_into_slice(&mut buf);
+    std::format!("did:pkh:stellar:testnet:{}", std::str::from_utf8(&buf).unwrap())
+}
+
+fn pkh_did(env: &Env, address: &Address) -> String {
+    String::from_str(env, &pkh_did_str(address))
+}
+
+#[test]
+fn test_did_register_update_and_resolve() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    let holder = Address::generate(&env);
+    let did = pkh_did(&env, &holder);
+    let service = Service {
+        id: String::from_str(&env, "vault"),
+        service_type: String::from_str(&env, "ActaVault"),
+        endpoint: String::from_str(&env, "https://acta.example/vault"),
+    };
+    let methods = vec![&env, key(&env, "key-1", 1)];
+    client.register_did(&holder, &did, &methods, &vec![&env, service.clone()]);
+
+    let doc = client.resolve_did(&did);
+    assert_eq!(doc.id, did);
+    assert_eq!(doc.controller, holder);
+    assert_eq!(doc.verification_methods, vec![&env, key(&env, "key-1", 1)]);
+    assert_eq!(doc.services, vec![&env, service.clone()]);
+    assert!(!doc.deactivated);
+
+    // Key rotation keeps the method ID.
+    let key_1 = String::from_str(&env, "key-1");
+    client.rotate_did_key(&did, &key_1, &BytesN::from_array(&env, &[9; 32]));
+    client.add_verification_method(&did, &key(&env, "key-2", 2));
+    client.remove_verification_method(&did, &key_1);
+    let doc = client.resolve_did(&did);
+    assert_eq!(doc.verification_methods, vec![&env, key(&env, "key-2", 2)]);
+
+    // Services are replaced by ID.
+    let moved = Service {
+        endpoint: String::from_str(&env, "https://acta.example/v2/vault"),
+        ..service
+    };
+    client.set_did_service(&did, &moved);
+    assert_eq!(client.resolve_did(&did).services, vec![&env, moved.clone()]);
+    client.remove_did_service(&did, &moved.id);
+    assert_eq!(client.resolve_did(&did).services.len(), 0);
+
+    let new_controller = Address::generate(&env);
+    client.set_did_controller(&did, &new_controller);
+    assert_eq!(client.resolve_did(&did).controller, new_controller);
+}
+
+#[test]
+fn test_did_deactivation_blocks_updates() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    let holder = Address::generate(&env);
+    let did = pkh_did(&env, &holder);
+    client.register_did(&holder, &did, &vec![&env, key(&env, "key-1", 1)], &Vec::new(&env));
+
+    client.deactivate_did(&did);
+    assert!(client.resolve_did(&did).deactivated);
+
+    assert_contract_error(
+        client.try_add_verification_method(&did, &key(&env, "key-2", 2)),
+        ContractError::DidDeactivated,
+    );
+    assert_contract_error(client.try_deactivate_did(&did), ContractError::DidDeactivated);
+}
+
+#[test]
+fn test_did_registry_errors() {
+    let (env, admin, _issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    let holder = Address::generate(&env);
+    let did = pkh_did(&env, &holder);
+    let unknown = String::from_str(&env, "did:pkh:stellar:testnet:UNKNOWN");
+    client.register_did(&holder, &did, &vec![&env, key(&env, "key-1", 1)], &Vec::new(&env));
+
+    // Control must be proven: a pkh DID of another address, or an unattested did:web.
+    let squatter = Address::generate(&env);
+    assert_contract_error(
+        client.try_register_did(&squatter, &unknown, &Vec::new(&env), &Vec::new(&env)),
+        ContractError::DidControlNotProven,
+    );
+    let web = String::from_str(&env, "did:web:university.edu");
+    client.attest_did_controller(&web, &holder);
+    assert_contract_error(
+        client.try_register_did(&squatter, &web, &Vec::new(&env), &Vec::new(&env)),
+        ContractError::DidControlNotProven,
+    );
+    client.register_did(&holder, &web, &Vec::new(&env), &Vec::new(&env));
+    assert_eq!(client.resolve_did(&web).controller, holder);
+
+    assert_contract_error(
+        client.try_register_did(&holder, &did, &Vec::new(&env), &Vec::new(&env)),
+        ContractError::DidAlreadyRegistered,
+    );
+    assert_contract_error(client.try_resolve_did(&unknown), ContractError::DidNotFound);
+    assert_contract_error(
+        client.try_register_did(
+            &holder,
+            &String::from_str(&env, "holder"),
+            &Vec::new(&env),
+            &Vec::new(&env),
+        ),
+        ContractError::InvalidDid,
+    );
+    assert_contract_error(
+        client.try_add_verification_method(&did, &key(&env, "key-1", 7)),
+        ContractError::VerificationMethodAlreadyExists,
+    );
+    assert_contract_error(
+        client.try_rotate_did_key(
+            &did,
+            &String::from_str(&env, "key-9"),
+            &BytesN::from_array(&env, &[0; 32]),
+        ),
+        ContractError::VerificationMethodNotFound,
+    );
+    assert_contract_error(
+        client.try_remove_did_service(&did, &String::from_str(&env, "missing")),
+        ContractError::ServiceNotFound,
+    );
+
+    for i in 1..MAX_VERIFICATION_METHODS {
+        client.add_verification_method(&did, &key(&env, &std::format!("key-{}", i + 1), i as u8));
+    }
+    assert_contract_error(
+        client.try_add_verification_method(&did, &key(&env, "key-extra", 0)),
+        ContractError::LimitExceeded,
+    );
+}
+
+fn status_field(env: &Env, m: &Map<String, String>, key: &str) -> String {
+    m.get(String::from_str(env, key)).unwrap()
+}
+
+#[test]
+fn test_issue_rejects_unbound_issuer_did() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let vc_id = String::from_str(&env, "vc-1");
+    let data = Bytes::from_slice(&env, b"<ciphertext>");
+
+    // Bound to a different issuer only.
+    let other = Address::generate(&env);
+    client.attest_issuer_did(&admin, &other, &String::from_str(&env, "did:web:university.edu"));
+
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &vc_id, &data, "did:web:university.edu"),
+        ContractError::IssuerDidNotBound,
+    );
+}
+
+#[test]
+fn test_bind_issuer_did_by_own_pkh() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    // A self-registered did:web document proves nothing about control.
+    let web = String::from_str(&env, "did:web:university.edu");
+    assert_contract_error(
+        client.try_bind_issuer_did(&issuer, &web),
+        ContractError::DidControlNotProven,
+    );
+    let other = Address::generate(&env);
+    assert_contract_error(
+        client.try_bind_issuer_did(&issuer, &pkh_did(&env, &other)),
+        ContractError::DidControlNotProven,
+    );
+
+    let did = pkh_did(&env, &issuer);
+    client.bind_issuer_did(&issuer, &did);
+    let binding = client.get_issuer_did_binding(&issuer, &did).unwrap();
+    assert_eq!(binding.proof, BindingProof::Account);
+
+    let vc_id = String::from_str(&env, "vc-1");
+    let data = Bytes::from_slice(&env, b"<ciphertext>");
+    try_issue_with(&env, &client, &owner, &issuer, &vc_id, &data, &pkh_did_str(&issuer))
+        .unwrap()
+        .unwrap();
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(status_field(&env, &m, "issuer_did_verified"), String::from_str(&env, "true"));
+
+    // Unbinding is reflected in verification results.
+    client.unbind_issuer_did(&issuer, &did);
+    assert!(client.get_issuer_did_binding(&issuer, &did).is_none());
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(status_field(&env, &m, "issuer_did_verified"), String::from_str(&env, "false"));
+    assert_eq!(status_field(&env, &m, "status"), String::from_str(&env, "valid"));
+}
+
+#[test]
+fn test_attest_issuer_did_requires_contract_admin() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    let did = String::from_str(&env, "did:web:university.edu");
+    assert_contract_error(
+        client.try_attest_issuer_did(&issuer, &issuer, &did),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_unbind_issuer_did(&issuer, &did),
+        ContractError::IssuerDidNotBound,
+    );
+
+    client.attest_issuer_did(&admin, &issuer, &did);
+    let binding = client.get_issuer_did_binding(&issuer, &did).unwrap();
+    assert_eq!(binding.proof, BindingProof::Attestation(admin));
+}
+
+fn types(env: &Env, names: &[&str]) -> Vec<String> {
+    let mut v = Vec::new(env);
+    for name in names {
+        v.push_back(String::from_str(env, name));
+    }
+    v
+}
+
+#[test]
+fn test_accreditation_chain_and_verification() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    let root = Address::generate(&env);
+    let body = Address::generate(&env);
+    let root_scope = types(&env, &["EducationCredential", "EmploymentCredential"]);
+    client.add_root_authority(&root, &root_scope);
+    client.accredit(&root, &body, &types(&env, &["EducationCredential"]), &true);
+    client.accredit(&body, &issuer, &types(&env, &["EducationCredential"]), &false);
+
+    let education = Some(String::from_str(&env, "EducationCredential"));
+    let status = client.verify_accreditation(&issuer, &education);
+    assert!(status.accredited);
+    assert_eq!(status.chain, vec![&env, issuer.clone(), body.clone(), root.clone()]);
+
+    let employment = Some(String::from_str(&env, "EmploymentCredential"));
+    assert!(!client.verify_accreditation(&issuer, &employment).accredited);
+
+    let vc_id = issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(status_field(&env, &m, "issuer_accredited"), String::from_str(&env, "true"));
+    let strkey = |a: &Address| {
+        let s = a.to_string();
+        let mut buf = std::vec![0u8; s.len() as usize];
+        s.copy_into_slice(&mut buf);
+        std::string::String::from_utf8(buf).unwrap()
+    };
+    let chain = std::format!("{},{},{}", strkey(&issuer), strkey(&body), strkey(&root));
+    assert_eq!(
+        status_field(&env, &m, "issuer_accreditation_chain"),
+        String::from_str(&env, &chain)
+    );
+
+    // Revoking the intermediate body cascades to the issuer.
+    client.revoke_accreditation(&root, &body);
+    let status = client.verify_accreditation(&issuer, &education);
+    assert!(!status.accredited);
+    assert_eq!(status.chain.len(), 0);
+    let m = client.verify_vc(&owner, &vc_id);
+    assert_eq!(status_field(&env, &m, "issuer_accredited"), String::from_str(&env, "false"));
+    assert_eq!(status_field(&env, &m, "issuer_accreditation_chain"), String::from_str(&env, ""));
+
+    // Re-accrediting the body does not revive accreditations issued under the old record.
+    client.accredit(&root, &body, &types(&env, &["EducationCredential"]), &true);
+    assert!(!client.verify_accreditation(&issuer, &education).accredited);
+    client.accredit(&body, &issuer, &types(&env, &["EducationCredential"]), &false);
+    assert!(client.verify_accreditation(&issuer, &education).accredited);
+}
+
+#[test]
+fn test_accreditation_errors() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    let root = Address::generate(&env);
+    let body = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.add_root_authority(&root, &types(&env, &["EducationCredential"]));
+
+    assert_contract_error(
+        client.try_accredit(&stranger, &issuer, &Vec::new(&env), &false),
+        ContractError::NotAccreditor,
+    );
+    assert_contract_error(
+        client.try_accredit(&root, &body, &types(&env, &["Health"]), &true),
+        ContractError::AccreditationScopeExceeded,
+    );
+    assert_contract_error(
+        client.try_accredit(&root, &body, &Vec::new(&env), &true),
+        ContractError::AccreditationScopeExceeded,
+    );
+
+    client.accredit(&root, &body, &types(&env, &["EducationCredential"]), &false);
+    assert_contract_error(
+        client.try_accredit(&body, &issuer, &types(&env, &["EducationCredential"]), &false),
+        ContractError::NotAccreditor,
+    );
+
+    // Another branch cannot take over or demote a subject, nor overwrite a root authority.
+    let other_root = Address::generate(&env);
+    client.add_root_authority(&other_root, &types(&env, &["EducationCredential"]));
+    assert_contract_error(
+        client.try_accredit(&other_root, &body, &types(&env, &["EducationCredential"]), &true),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_accredit(&other_root, &root, &types(&env, &["EducationCredential"]), &false),
+        ContractError::Unauthorized,
+    );
+    assert_eq!(client.get_accreditation(&body).unwrap().accreditor, root);
+    assert!(client.get_accreditation(&root).unwrap().root);
+    assert_contract_error(
+        client.try_revoke_accreditation(&stranger, &body),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_revoke_accreditation(&root, &issuer),
+        ContractError::AccreditationNotFound,
+    );
+
+    // The contract admin can revoke anywhere in the tree.
+    client.revoke_accreditation(&admin, &body);
+    assert!(client.get_accreditation(&body).unwrap().revoked);
+}
+
+#[test]
+fn test_accreditor_can_attest_issuer_did() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+
+    let root = Address::generate(&env);
+    client.add_root_authority(&root, &Vec::new(&env));
+
+    let did = String::from_str(&env, "did:web:university.edu");
+    client.attest_issuer_did(&root, &issuer, &did);
+    let binding = client.get_issuer_did_binding(&issuer, &did).unwrap();
+    assert_eq!(binding.proof, BindingProof::Attestation(root.clone()));
+
+    client.revoke_accreditation(&admin, &root);
+    assert_contract_error(
+        client.try_attest_issuer_did(&root, &issuer, &did),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_issuer_authorization_window() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    let school = Address::generate(&env);
+    let did = String::from_str(&env, "did:web:school.edu");
+    client.attest_issuer_did(&admin, &school, &did);
+    client.authorize_issuer_with_limits(
+        &owner,
+        &school,
+        &Some(1_000),
+        &Some(2_000),
+        &None,
+        &Vec::new(&env),
+    );
+
+    let data = Bytes::from_slice(&env, b"<ciphertext>");
+    let try_issue = |vc_id: &str| {
+        let vc_id = String::from_str(&env, vc_id);
+        try_issue_with(&env, &client, &owner, &school, &vc_id, &data, "did:web:school.edu")
+    };
+
+    env.ledger().set_timestamp(999);
+    assert!(!client.get_issuer_allowance(&owner, &school).active);
+    assert_contract_error(try_issue("vc-1"), ContractError::IssuerAuthorizationInactive);
+
+    env.ledger().set_timestamp(1_500);
+    try_issue("vc-1").unwrap().unwrap();
+
+    env.ledger().set_timestamp(2_001);
+    assert_contract_error(try_issue("vc-2"), ContractError::IssuerAuthorizationInactive);
+
+    // The window only limits issuance: the holder can still move the credential.
+    let other = Address::generate(&env);
+    client.create_vault(&other, &String::from_str(&env, "did:pkh:stellar:testnet:OTHER"));
+    client.push(&owner, &other, &String::from_str(&env, "vc-1"), &school);
+    assert!(client.get_vc(&other, &String::from_str(&env, "vc-1")).is_some());
+}
+
+#[test]
+fn test_issuer_issuance_cap_and_allowance() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    let owner = Address::generate(&env);
+    client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    client.attest_issuer_did(&admin, &issuer, &issuer_did);
+    client.authorize_issuer_with_limits(&owner, &issuer, &None, &None, &Some(2), &Vec::new(&env));
+
+    let allowance = client.get_issuer_allowance(&owner, &issuer);
+    assert!(allowance.active);
+    assert_eq!(allowance.remaining, Some(2));
+
+    issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    issue_vc(&env, &client, &owner, &issuer, "vc-2");
+
+    let allowance = client.get_issuer_allowance(&owner, &issuer);
+    assert!(!allowance.active);
+    assert_eq!(allowance.issued, 2);
+    assert_eq!(allowance.remaining, Some(0));
+
+    let data = Bytes::from_slice(&env, b"<ciphertext>");
+    assert_contract_error(
+        try_issue_with(
+            &env,
+            &client,
+            &owner,
+            &issuer,
+            &String::from_str(&env, "vc-3"),
+            &data,
+            "did:pkh:stellar:testnet:ISSUER",
+        ),
+        ContractError::IssuanceLimitReached,
+    );
+
+    // Re-authorizing after a revoke starts from fresh terms.
+    client.revoke_issuer(&owner, &issuer);
+    assert_contract_error(
+        client.try_get_issuer_allowance(&owner, &issuer),
+        ContractError::IssuerNotAuthorized,
+    );
+    client.authorize_issuer(&owner, &issuer);
+    let allowance = client.get_issuer_allowance(&owner, &issuer);
+    assert_eq!(allowance.remaining, None);
+    assert_eq!(allowance.issued, 0);
+
+    // Unlimited issuers get no terms entry.
+    issue_vc(&env, &client, &owner, &issuer, "vc-4");
+    assert_eq!(client.get_issuer_allowance(&owner, &issuer).issued, 0);
+}
+
+#[test]
+fn test_issuer_limits_validation() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    client.initialize(&admin, &String::from_str(&env, "did:acta:default"));
+    let owner = Address::generate(&env);
+    client.create_vault(&owner, &String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
+
+    assert_contract_error(
+        client.try_authorize_issuer_with_limits(
+            &owner,
+            &issuer,
+            &Some(10),
+            &Some(5),
+            &None,
+            &Vec::new(&env),
+        ),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_authorize_issuer_with_limits(
+            &owner,
+            &issuer,
+            &None,
+            &None,
+            &Some(0),
+            &Vec::new(&env),
+        ),
+        ContractError::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_issuer_scoped_to_credential_types() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let issuer_did = String::from_str(&env, "did:pkh:stellar:testnet:ISSUER");
+    let data = Bytes::from_slice(&env, b"<ciphertext>");
+
+    let employment_only = types(&env, &["EmploymentCredential"]);
+    client.set_issuer_credential_types(&owner, &issuer, &employment_only);
+    assert_eq!(client.get_issuer_allowance(&owner, &issuer).credential_types, employment_only);
+
+    let employment = String::from_str(&env, "EmploymentCredential");
+    let vc_id = String::from_str(&env, "vc-job");
+    client.issue(
+        &owner,
+        &vc_id,
+        &data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &employment,
+        &None,
+        &None,
+    );
+    assert_eq!(client.get_vc(&owner, &vc_id).unwrap().credential_type, employment);
+
+    assert_contract_error(
+        client.try_issue(
+            &owner,
+            &String::from_str(&env, "vc-health"),
+            &data,
+            &contract_id,
+            &issuer,
+            &issuer_did,
+            &String::from_str(&env, "HealthCredential"),
+            &None,
+            &None,
+        ),
+        ContractError::CredentialTypeNotAllowed,
+    );
+    assert_contract_error(
+        client.try_issue(
+            &owner,
+            &String::from_str(&env, "vc-untyped"),
+            &data,
+            &contract_id,
+            &issuer,
+            &issuer_did,
+            &String::from_str(&env, ""),
+            &None,
+            &None,
+        ),
+        ContractError::InvalidId,
+    );
+
+    // Clearing the scope allows any type again.
+    client.set_issuer_credential_types(&owner, &issuer, &Vec::new(&env));
+    client.issue(
+        &owner,
+        &String::from_str(&env, "vc-health"),
+        &data,
+        &contract_id,
+        &issuer,
+        &issuer_did,
+        &String::from_str(&env, "HealthCredential"),
+        &None,
+        &None,
+    );
+
+    assert_contract_error(
+        client.try_set_issuer_credential_types(&owner, &Address::generate(&env), &Vec::new(&env)),
+        ContractError::IssuerNotAuthorized,
+    );
+}
+
+#[test]
+fn test_issuer_access_request_approval() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    env.ledger().set_timestamp(1_000);
+
+    let applicant = Address::generate(&env);
+    let scope = types(&env, &["EmploymentCredential"]);
+    client.request_issuer_access(&applicant, &owner, &scope, &2_000, &Some(500));
+
+    let requests = client.list_issuer_requests(&owner);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests.get_unchecked(0).issuer, applicant);
+    assert_eq!(requests.get_unchecked(0).credential_types, scope);
+
+    // The requested period runs from approval, not from the request's expiry.
+    env.ledger().set_timestamp(1_900);
+    client.approve_issuer_request(&owner, &applicant);
+    assert_eq!(client.list_issuer_requests(&owner).len(), 0);
+    let allowance = client.get_issuer_allowance(&owner, &applicant);
+    assert_eq!(allowance.credential_types, scope);
+    assert_eq!(allowance.valid_until, Some(2_400));
+
+    let open_ended = Address::generate(&env);
+    client.request_issuer_access(&open_ended, &owner, &scope, &2_000, &None);
+    client.approve_issuer_request(&owner, &open_ended);
+    assert_eq!(client.get_issuer_allowance(&owner, &open_ended).valid_until, None);
+
+    // Denied requests are dropped without authorizing.
+    let rejected = Address::generate(&env);
+    client.request_issuer_access(&rejected, &owner, &Vec::new(&env), &2_000, &None);
+    client.deny_issuer_request(&owner, &rejected);
+    assert_eq!(client.list_issuer_requests(&owner).len(), 0);
+    assert_contract_error(
+        client.try_get_issuer_allowance(&owner, &rejected),
+        ContractError::IssuerNotAuthorized,
+    );
+
+    // Requests can be denied or purged in bulk.
+    let spam: std::vec::Vec<Address> = (0..3).map(|_| Address::generate(&env)).collect();
+    for applicant in spam.iter() {
+        client.request_issuer_access(applicant, &owner, &Vec::new(&env), &2_000, &None);
+    }
+    let denied = vec![&env, spam[0].clone(), spam[1].clone(), rejected.clone()];
+    assert_eq!(client.deny_issuer_requests(&owner, &denied), 2);
+    assert_eq!(client.list_issuer_requests(&owner).len(), 1);
+    assert_eq!(client.purge_issuer_requests(&owner), 1);
+    assert_eq!(client.list_issuer_requests(&owner).len(), 0);
+}
+
+#[test]
+fn test_issuer_access_request_errors_and_expiry() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    env.ledger().set_timestamp(1_000);
+
+    assert_contract_error(
+        client.try_request_issuer_access(&issuer, &owner, &Vec::new(&env), &2_000, &None),
+        ContractError::IssuerAlreadyAuthorized,
+    );
+    let applicant = Address::generate(&env);
+    assert_contract_error(
+        client.try_request_issuer_access(&applicant, &owner, &Vec::new(&env), &1_000, &None),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_request_issuer_access(&applicant, &owner, &Vec::new(&env), &2_000, &Some(0)),
+        ContractError::InvalidArgument,
+    );
+    let too_late = 1_000 + MAX_REQUEST_HORIZON + 1;
+    assert_contract_error(
+        client.try_request_issuer_access(&applicant, &owner, &Vec::new(&env), &too_late, &None),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_approve_issuer_request(&owner, &applicant),
+        ContractError::NotFound,
+    );
+
+    client.request_issuer_access(&applicant, &owner, &Vec::new(&env), &2_000, &None);
+    env.ledger().set_timestamp(2_001);
+    assert_eq!(client.list_issuer_requests(&owner).len(), 0);
+    assert_contract_error(
+        client.try_approve_issuer_request(&owner, &applicant),
+        ContractError::Expired,
+    );
+}
+
+#[test]
+fn test_read_vault_and_global_config() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    issue_vc(&env, &client, &owner, &issuer, "vc-1");
+
+    let info = client.get_vault(&owner);
+    assert_eq!(info.owner, owner);
+    assert_eq!(info.admin, owner);
+    assert_eq!(info.did, String::from_str(&env, "did:pkh:stellar:testnet:OWNER"));
+    assert!(!info.revoked);
+    assert_eq!(info.issuer_count, 1);
+    assert_eq!(info.vc_count, 1);
+    assert_eq!(client.list_issuers(&owner), vec![&env, issuer.clone()]);
+
+    client.revoke_vault(&owner);
+    assert!(client.get_vault(&owner).revoked);
+    assert_contract_error(
+        client.try_get_vault(&Address::generate(&env)),
+        ContractError::VaultNotInitialized,
+    );
+
+    assert_eq!(client.get_contract_admin(), admin);
+    assert_eq!(
+        client.get_default_issuer_did(),
+        Some(String::from_str(&env, "did:acta:default"))
+    );
+
+    assert_eq!(client.get_fee_config(), None);
+    let token = Address::generate(&env);
+    let dest = Address::generate(&env);
+    client.set_fee_config(&token, &dest, &10);
+    let config = client.get_fee_config().unwrap();
+    assert!(!config.enabled);
+    assert_eq!(config.token_contract, token);
+    assert_eq!(config.fee_dest, dest);
+    assert_eq!(config.fee_amount, 10);
+}
+
+#[test]
+fn test_restore_revoked_vault() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    assert_contract_error(
+        client.try_restore_vault(&owner, &owner),
+        ContractError::InvalidArgument,
+    );
+
+    client.revoke_vault(&owner);
+    assert_contract_error(
+        client.try_restore_vault(&Address::generate(&env), &owner),
+        ContractError::Unauthorized,
+    );
+    client.restore_vault(&owner, &owner);
+    issue_vc(&env, &client, &owner, &issuer, "vc-after-restore");
+
+    // The contract admin may restore too.
+    client.revoke_vault(&owner);
+    client.restore_vault(&admin, &owner);
+    assert!(!client.get_vault(&owner).revoked);
+}
+
+#[test]
+fn test_close_vault_in_batches() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    let total = CLOSE_BATCH_SIZE + 5;
+    for i in 0..total {
+        env.budget().reset_default();
+        issue_vc(&env, &client, &owner, &issuer, &std::format!("vc-{i}"));
+    }
+    client.revoke(&String::from_str(&env, "vc-0"), &String::from_str(&env, "2026-01-01"));
+    let pending = String::from_str(&env, "vc-pending");
+    client.upload_vc_part(&issuer, &owner, &pending, &Bytes::from_slice(&env, b"<cipher"));
+
+    env.budget().reset_default();
+    let progress = client.close_vault(&owner);
+    assert_eq!(progress.removed, CLOSE_BATCH_SIZE);
+    assert_eq!(progress.remaining, 5);
+    assert!(!progress.done);
+
+    // Revoked and not restorable while closing.
+    assert!(client.get_vault(&owner).closing);
+    assert_contract_error(
+        client.try_restore_vault(&owner, &owner),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_authorize_issuer(&owner, &Address::generate(&env)),
+        ContractError::VaultRevoked,
+    );
+
+    env.budget().reset_default();
+    let progress = client.close_vault(&owner);
+    assert_eq!(progress.removed, 5);
+    assert!(progress.done);
+
+    assert_contract_error(client.try_get_vault(&owner), ContractError::VaultNotInitialized);
+    assert_eq!(client.list_vc_ids(&owner).len(), 0);
+    assert!(client.get_vc(&owner, &String::from_str(&env, "vc-1")).is_none());
+
+    env.as_contract(&contract_id, || {
+        let revoked = String::from_str(&env, "vc-0");
+        assert!(matches!(storage::read_vc_status(&env, &revoked), VCStatus::Revoked(_)));
+        let deleted = String::from_str(&env, "vc-1");
+        assert_eq!(storage::read_vc_status(&env, &deleted), VCStatus::Invalid);
+        assert_eq!(storage::read_vc_payload_hash(&env, &deleted), None);
+        assert_eq!(storage::read_vc_upload(&env, &owner, &pending), None);
+        assert_eq!(storage::read_vc_upload_part(&env, &owner, &pending, 0), None);
+        assert!(storage::read_vc_uploads(&env, &owner).is_empty());
+    });
+}
+
+#[test]
+fn test_delete_vc_keeps_status_registry() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let vc_id = issue_vc(&env, &client, &owner, &issuer, "vc-erase");
+    issue_vc(&env, &client, &owner, &issuer, "vc-keep");
+    client.revoke_vault(&owner);
+
+    client.delete_vc(&owner, &vc_id);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.0, contract_id);
+    assert_eq!(event.1, (symbol_short!("vc_del"), owner.clone()).into_val(&env));
+    let deleted: String = event.2.into_val(&env);
+    assert_eq!(deleted, vc_id);
+
+    assert!(client.get_vc(&owner, &vc_id).is_none());
+    assert_eq!(client.list_vc_ids(&owner), vec![&env, String::from_str(&env, "vc-keep")]);
+    env.as_contract(&contract_id, || {
+        assert_eq!(storage::read_vc_status(&env, &vc_id), VCStatus::Valid);
+    });
+
+    assert_contract_error(client.try_delete_vc(&owner, &vc_id), ContractError::VCNotFound);
+}
+
+#[test]
+fn test_rotate_vault_owner_in_batches() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let old = setup_vault(&env, &client, &admin, &issuer);
+    let capped = Address::generate(&env);
+    let none = Vec::new(&env);
+    client.authorize_issuer_with_limits(&old, &capped, &None, &None, &Some(3), &none);
+
+    let total = ROTATION_BATCH_SIZE + 3;
+    for i in 0..total {
+        env.budget().reset_default();
+        issue_vc(&env, &client, &old, &issuer, &std::format!("vc-{i}"));
+    }
+    let ids = client.list_vc_ids(&old);
+    let pending = String::from_str(&env, "vc-pending");
+    client.upload_vc_part(&issuer, &old, &pending, &Bytes::from_slice(&env, b"<cipher"));
+    let verifier = Address::generate(&env);
+    client.share_vc(&old, &String::from_str(&env, "vc-0"), &verifier, &1_000);
+
+    let new = Address::generate(&env);
+    assert_contract_error(
+        client.try_rotate_vault_owner(&old, &old),
+        ContractError::InvalidArgument,
+    );
+
+    env.budget().reset_default();
+    let progress = client.rotate_vault_owner(&old, &new);
+    assert_eq!(progress.moved, ROTATION_BATCH_SIZE);
+    assert_eq!(progress.remaining, 3);
+    assert!(!progress.done);
+
+    // Frozen mid-rotation, and only the pending target may continue it.
+    assert_contract_error(
+        client.try_issue(
+            &new,
+            &String::from_str(&env, "vc-mid"),
+            &Bytes::from_slice(&env, b"<ciphertext>"),
+            &contract_id,
+            &issuer,
+            &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+            &String::from_str(&env, "EducationCredential"),
+            &None,
+            &None,
+        ),
+        ContractError::VaultRevoked,
+    );
+    assert_contract_error(
+        client.try_rotate_vault_owner(&old, &Address::generate(&env)),
+        ContractError::InvalidArgument,
+    );
+    // The target cannot be restored or closed out from under the rotation either.
+    assert_contract_error(client.try_restore_vault(&admin, &new), ContractError::InvalidArgument);
+    assert_contract_error(client.try_close_vault(&new), ContractError::InvalidArgument);
+
+    env.budget().reset_default();
+    assert!(client.rotate_vault_owner(&old, &new).done);
+
+    let info = client.get_vault(&new);
+    assert_eq!(info.owner, new);
+    assert_eq!(info.admin, new);
+    assert!(!info.revoked);
+    assert_eq!(client.list_vc_ids(&new), ids);
+    assert_eq!(client.get_issuer_allowance(&new, &capped).remaining, Some(3));
+
+    // Old references resolve through the alias.
+    assert_eq!(client.get_vault(&old).owner, new);
+    let vc_id = String::from_str(&env, "vc-0");
+    assert!(client.get_vc(&old, &vc_id).is_some());
+    assert_eq!(client.get_vc_share(&new, &vc_id, &verifier), Some(1_000));
+    env.as_contract(&contract_id, || {
+        assert_eq!(storage::read_vc_owner(&env, &vc_id), Some(new.clone()));
+        assert_eq!(storage::read_vc_upload(&env, &old, &pending), None);
+        assert!(storage::read_vc_uploads(&env, &old).is_empty());
+    });
+
+    // The new owner now revokes and receives issuance.
+    client.revoke(&vc_id, &String::from_str(&env, "2026-01-01"));
+    issue_vc(&env, &client, &new, &issuer, "vc-after-rotation");
+}
+
+#[test]
+fn test_guardian_recovery_after_delay() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    env.ledger().set_timestamp(1_000);
+
+    let guardians = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    client.set_guardians(&owner, &guardians, &2, &86_400);
+    assert_eq!(client.get_guardians(&owner).unwrap().threshold, 2);
+
+    // A pending recovery cannot be wiped by another guardian, only replaced once it lapsed.
+    let rogue = Address::generate(&env);
+    client.initiate_recovery(&guardians.get_unchecked(2), &owner, &rogue);
+    let new_admin = Address::generate(&env);
+    assert_contract_error(
+        client.try_initiate_recovery(&guardians.get_unchecked(0), &owner, &new_admin),
+        ContractError::InvalidArgument,
+    );
+    let start = 1_001 + RECOVERY_APPROVAL_WINDOW;
+    env.ledger().set_timestamp(start);
+    assert_contract_error(
+        client.try_approve_recovery(&guardians.get_unchecked(1), &owner, &rogue),
+        ContractError::Expired,
+    );
+    client.initiate_recovery(&guardians.get_unchecked(0), &owner, &new_admin);
+    assert_eq!(client.get_recovery(&owner).unwrap().new_admin, new_admin);
+    assert_eq!(client.get_recovery(&owner).unwrap().executable_at, None);
+    assert_contract_error(client.try_complete_recovery(&owner), ContractError::RecoveryNotReady);
+
+    // Approvals name the admin they approve.
+    assert_contract_error(
+        client.try_approve_recovery(&guardians.get_unchecked(1), &owner, &rogue),
+        ContractError::InvalidArgument,
+    );
+    client.approve_recovery(&guardians.get_unchecked(1), &owner, &new_admin);
+    let recovery = client.get_recovery(&owner).unwrap();
+    assert_eq!(recovery.approvals.len(), 2);
+    assert_eq!(recovery.executable_at, Some(start + 86_400));
+    assert_contract_error(client.try_complete_recovery(&owner), ContractError::RecoveryNotReady);
+    assert_contract_error(
+        client.try_initiate_recovery(&guardians.get_unchecked(2), &owner, &rogue),
+        ContractError::InvalidArgument,
+    );
+
+    // An admin set in place does not survive the recovery.
+    let admins = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.set_vault_admins(&owner, &admins, &2);
+
+    env.ledger().set_timestamp(start + 86_400);
+    client.complete_recovery(&owner);
+    assert_eq!(client.get_vault(&owner).admin, new_admin);
+    assert_eq!(client.get_vault_admins(&owner), None);
+    assert_eq!(client.get_recovery(&owner), None);
+}
+
+#[test]
+fn test_guardian_recovery_cancel_and_errors() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let guardian = Address::generate(&env);
+    let guardians = vec![&env, guardian.clone()];
+
+    assert_contract_error(
+        client.try_set_guardians(&owner, &guardians, &2, &0),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_set_guardians(&owner, &vec![&env, guardian.clone(), guardian.clone()], &1, &60),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_set_guardians(&owner, &guardians, &1, &0),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_initiate_recovery(&guardian, &owner, &guardian),
+        ContractError::NotFound,
+    );
+
+    client.set_guardians(&owner, &guardians, &1, &3_600);
+    assert_contract_error(
+        client.try_initiate_recovery(&Address::generate(&env), &owner, &guardian),
+        ContractError::Unauthorized,
+    );
+
+    // The current admin cancels during the delay.
+    client.initiate_recovery(&guardian, &owner, &guardian);
+    assert_contract_error(
+        client.try_initiate_recovery(&guardian, &owner, &guardian),
+        ContractError::InvalidArgument,
+    );
+    client.cancel_recovery(&owner);
+    assert_eq!(client.get_recovery(&owner), None);
+    assert_contract_error(client.try_complete_recovery(&owner), ContractError::NotFound);
+    assert_eq!(client.get_vault(&owner).admin, owner);
+
+    // Once the delay has elapsed the recovery can only be completed.
+    client.initiate_recovery(&guardian, &owner, &guardian);
+    env.ledger().set_timestamp(3_600);
+    assert_contract_error(client.try_cancel_recovery(&owner), ContractError::Expired);
+    client.complete_recovery(&owner);
+    assert_eq!(client.get_vault(&owner).admin, guardian);
+}
+
+#[test]
+fn test_vault_admin_set_threshold_proposals() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    env.ledger().set_timestamp(1_000);
+
+    let admins = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let (a0, a1) = (admins.get_unchecked(0), admins.get_unchecked(1));
+    client.set_vault_admins(&owner, &admins, &2);
+
+    // Direct calls are rejected once the vault is under M-of-N control.
+    let new_issuer = Address::generate(&env);
+    assert_contract_error(
+        client.try_authorize_issuer(&owner, &new_issuer),
+        ContractError::Unauthorized,
+    );
+
+    let action = AdminAction::AuthorizeIssuer(new_issuer.clone());
+    let id = client.propose_admin_action(&a0, &owner, &action, &2_000);
+    let pending = client.list_admin_proposals(&owner);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get_unchecked(0).action, action);
+
+    assert!(!client.approve_admin_action(&a0, &owner, &id));
+    assert!(client.approve_admin_action(&a1, &owner, &id));
+    assert_eq!(client.list_admin_proposals(&owner).len(), 0);
+    assert!(client.list_issuers(&owner).contains(&new_issuer));
+
+    let id = client.propose_admin_action(&a0, &owner, &AdminAction::RevokeVault, &2_000);
+    client.approve_admin_action(&a1, &owner, &id);
+    assert!(client.get_vault(&owner).revoked);
+}
+
+#[test]
+fn test_vault_admin_set_covers_every_admin_action() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    env.ledger().set_timestamp(1_000);
+    let vc_id = issue_vc(&env, &client, &owner, &issuer, "vc-1");
+
+    let a0 = Address::generate(&env);
+    client.set_vault_admins(&owner, &vec![&env, a0.clone(), Address::generate(&env)], &1);
+
+    // Vault-level actions need a single admin again.
+    let guardians = vec![&env, Address::generate(&env)];
+    assert_contract_error(client.try_close_vault(&owner), ContractError::Unauthorized);
+    assert_contract_error(client.try_delete_vc(&owner, &vc_id), ContractError::Unauthorized);
+    assert_contract_error(
+        client.try_set_guardians(&owner, &guardians, &1, &60),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_rotate_vault_owner(&owner, &Address::generate(&env)),
+        ContractError::Unauthorized,
+    );
+
+    // Issuer management stays reachable through proposals.
+    let (capped, added, applicant) =
+        (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let scope = types(&env, &["EducationCredential"]);
+    let terms = IssuerTerms {
+        valid_from: None,
+        valid_until: Some(5_000),
+        max_issuances: Some(2),
+        credential_types: Vec::new(&env),
+        issued: 7,
+    };
+    let none = Vec::new(&env);
+    client.request_issuer_access(&applicant, &owner, &scope, &2_000, &None);
+    let actions = [
+        AdminAction::AuthorizeIssuerWithLimits(capped.clone(), terms),
+        AdminAction::SetIssuerCredentialTypes(capped.clone(), scope.clone()),
+        AdminAction::UpdateIssuers(vec![&env, added.clone()], vec![&env, issuer.clone()]),
+        AdminAction::ApproveIssuerRequest(applicant.clone()),
+    ];
+    for action in actions.iter() {
+        client.propose_admin_action(&a0, &owner, action, &2_000);
+    }
+    let allowance = client.get_issuer_allowance(&owner, &capped);
+    assert_eq!((allowance.issued, allowance.remaining), (0, Some(2)));
+    assert_eq!(allowance.credential_types, scope);
+    let issuers = client.list_issuers(&owner);
+    assert!(issuers.contains(&added) && issuers.contains(&applicant));
+    assert!(!issuers.contains(&issuer));
+    assert_eq!(client.list_issuer_requests(&owner), Vec::new(&env));
+    assert_contract_error(
+        client.try_update_issuers(&owner, &none, &vec![&env, added]),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_vault_admin_set_gates_clean_up_and_restore() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    env.ledger().set_timestamp(1_000);
+    env.as_contract(&contract_id, || {
+        storage::remove_vault_bytes_layout(&env, &owner);
+        let vc = StringVerifiableCredential {
+            id: vc_id_at(&env, 0),
+            data: String::from_str(&env, "<ciphertext>"),
+            issuance_contract: contract_id.clone(),
+            issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+            credential_type: String::from_str(&env, "EducationCredential"),
+        };
+        storage::write_string_vault_vc(&env, &owner, &vc);
+        storage::append_vault_vc_id(&env, &owner, &vc.id);
+    });
+    let guardians = vec![&env, Address::generate(&env), Address::generate(&env)];
+    client.set_guardians(&owner, &guardians, &2, &86_400);
+    client.initiate_recovery(&guardians.get_unchecked(0), &owner, &Address::generate(&env));
+    let scope = types(&env, &["EducationCredential"]);
+    let (first, second) = (Address::generate(&env), Address::generate(&env));
+    client.request_issuer_access(&first, &owner, &scope, &2_000, &None);
+    client.request_issuer_access(&second, &owner, &scope, &2_000, &None);
+
+    let (a0, a1) = (Address::generate(&env), Address::generate(&env));
+    client.set_vault_admins(&owner, &vec![&env, a0.clone(), a1.clone()], &2);
+    let pass = |action: AdminAction| {
+        let id = client.propose_admin_action(&a0, &owner, &action, &2_000);
+        assert!(client.approve_admin_action(&a1, &owner, &id));
+    };
+
+    // A single signer can no longer drop requests, block recovery or migrate.
+    let one = vec![&env, first.clone()];
+    assert_contract_error(
+        client.try_deny_issuer_request(&owner, &first),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_deny_issuer_requests(&owner, &one),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(client.try_purge_issuer_requests(&owner), ContractError::Unauthorized);
+    assert_contract_error(client.try_cancel_recovery(&owner), ContractError::Unauthorized);
+    assert_contract_error(client.try_migrate(&Some(owner.clone())), ContractError::Unauthorized);
+
+    pass(AdminAction::DenyIssuerRequests(one));
+    assert_eq!(client.list_issuer_requests(&owner).len(), 1);
+    pass(AdminAction::PurgeIssuerRequests);
+    assert_eq!(client.list_issuer_requests(&owner).len(), 0);
+    pass(AdminAction::CancelRecovery);
+    assert_eq!(client.get_recovery(&owner), None);
+    pass(AdminAction::MigrateVault);
+    assert!(client.migration_progress(&Some(owner.clone())).done);
+
+    // A threshold revocation takes a threshold restoration.
+    pass(AdminAction::RevokeVault);
+    assert_contract_error(client.try_restore_vault(&owner, &owner), ContractError::Unauthorized);
+    assert_contract_error(client.try_restore_vault(&admin, &owner), ContractError::Unauthorized);
+    pass(AdminAction::RestoreVault);
+    assert!(!client.get_vault(&owner).revoked);
+}
+
+#[test]
+fn test_vault_admin_proposal_errors_and_expiry() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    env.ledger().set_timestamp(1_000);
+
+    let (a0, a1) = (Address::generate(&env), Address::generate(&env));
+    assert_contract_error(
+        client.try_set_vault_admins(&owner, &vec![&env, a0.clone()], &2),
+        ContractError::InvalidArgument,
+    );
+    client.set_vault_admins(&owner, &vec![&env, a0.clone(), a1.clone()], &2);
+
+    let action = AdminAction::RevokeIssuer(issuer.clone());
+    assert_contract_error(
+        client.try_propose_admin_action(&Address::generate(&env), &owner, &action, &2_000),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_approve_admin_action(&a1, &owner, &7),
+        ContractError::NotFound,
+    );
+
+    let id = client.propose_admin_action(&a0, &owner, &action, &2_000);
+    env.ledger().set_timestamp(2_001);
+    assert_eq!(client.list_admin_proposals(&owner).len(), 0);
+    assert_contract_error(
+        client.try_approve_admin_action(&a1, &owner, &id),
+        ContractError::Expired,
+    );
+
+    // Handing the vault to a single admin clears the set.
+    let single = Address::generate(&env);
+    let action = AdminAction::SetVaultAdmin(single.clone());
+    let id = client.propose_admin_action(&a0, &owner, &action, &3_000);
+    client.approve_admin_action(&a1, &owner, &id);
+    assert_eq!(client.get_vault_admins(&owner), None);
+    assert_eq!(client.get_vault(&owner).admin, single);
+    client.revoke_issuer(&owner, &issuer);
+}
+
+/// Registers a token, mints `amount` to `holder` and returns the token address.
+fn fee_token(env: &Env, holder: &Address, amount: i128) -> Address {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(holder, &amount);
+    token
+}
+
+#[test]
+fn test_fee_schedule_quotes_and_charges() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let token = fee_token(&env, &issuer, 1_000);
+    let fee_dest = Address::generate(&env);
+    client.set_fee_config(&token, &fee_dest, &100);
+    client.set_fee_enabled(&true);
+
+    let manager = Address::generate(&env);
+    client.set_fee_manager(&Some(manager.clone()));
+    assert_contract_error(
+        client.try_set_fee_tiers(&Address::generate(&env), &Vec::new(&env)),
+        ContractError::Unauthorized,
+    );
+
+    let education = String::from_str(&env, "EducationCredential");
+    client.set_credential_type_fee(&manager, &education, &Some(40));
+    let tiers = vec![&env, FeeTier { min_issuances: 1, discount_bps: 5_000 }];
+    client.set_fee_tiers(&manager, &tiers);
+
+    // Type price, no tier yet.
+    assert_eq!(client.quote_fee(&issuer, &education).amount, 40);
+    issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    // Type price, 50% volume discount.
+    assert_eq!(client.quote_fee(&issuer, &education).amount, 20);
+    issue_vc(&env, &client, &owner, &issuer, "vc-2");
+    // Global amount for other types, discounted too.
+    let other = String::from_str(&env, "HealthCredential");
+    assert_eq!(client.quote_fee(&issuer, &other).amount, 50);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&fee_dest), 60);
+
+    // A negotiated amount in another token replaces pricing and tiers.
+    let other_token = fee_token(&env, &issuer, 1_000);
+    let negotiated = IssuerFee { exempt: false, amount: Some(7), token: Some(other_token.clone()) };
+    client.set_issuer_fee(&manager, &issuer, &Some(negotiated));
+    let quote = client.quote_fee(&issuer, &education);
+    assert_eq!((quote.token, quote.amount), (Some(other_token.clone()), 7));
+    issue_vc(&env, &client, &owner, &issuer, "vc-3");
+    assert_eq!(token::Client::new(&env, &other_token).balance(&fee_dest), 7);
+
+    // Exempt issuers pay nothing.
+    let exempt = IssuerFee { exempt: true, amount: None, token: None };
+    client.set_issuer_fee(&admin, &issuer, &Some(exempt));
+    assert_eq!(client.quote_fee(&issuer, &education).amount, 0);
+    issue_vc(&env, &client, &owner, &issuer, "vc-4");
+    assert_eq!(token_client.balance(&issuer), 940);
+}
+
+#[test]
+fn test_fee_schedule_validation() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    setup_vault(&env, &client, &admin, &issuer);
+
+    let unordered = vec![
+        &env,
+        FeeTier { min_issuances: 10, discount_bps: 1_000 },
+        FeeTier { min_issuances: 10, discount_bps: 2_000 },
+    ];
+    assert_contract_error(
+        client.try_set_fee_tiers(&admin, &unordered),
+        ContractError::InvalidArgument,
+    );
+    let too_deep = vec![&env, FeeTier { min_issuances: 1, discount_bps: 10_001 }];
+    assert_contract_error(
+        client.try_set_fee_tiers(&admin, &too_deep),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_set_credential_type_fee(
+            &admin,
+            &String::from_str(&env, "EducationCredential"),
+            &Some(-1),
+        ),
+        ContractError::InvalidFeeAmount,
+    );
+
+    // Fees disabled: nothing to pay.
+    let quote = client.quote_fee(&issuer, &String::from_str(&env, "EducationCredential"));
+    assert_eq!((quote.token, quote.amount), (None, 0));
+}
+
+fn share(recipient: &Address, bps: u32) -> FeeShare {
+    FeeShare {
+        recipient: recipient.clone(),
+        bps,
+    }
+}
+
+#[test]
+fn test_fee_split_across_recipients() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let token = fee_token(&env, &issuer, 1_000);
+    client.set_fee_config(&token, &Address::generate(&env), &101);
+    client.set_fee_enabled(&true);
+
+    let (platform, accreditor, operator) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    let shares = vec![
+        &env,
+        share(&platform, 5_000),
+        share(&accreditor, 3_000),
+        share(&operator, 2_000),
+    ];
+    client.set_fee_shares(&shares);
+    assert_eq!(client.get_fee_shares(), shares);
+
+    issue_vc(&env, &client, &owner, &issuer, "vc-1");
+
+    // 101 * 50% = 50.5, 30% = 30.3, 20% = 20.2: rounded down, remainder (1) to the first.
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&platform), 51);
+    assert_eq!(token_client.balance(&accreditor), 30);
+    assert_eq!(token_client.balance(&operator), 20);
+    assert_eq!(token_client.balance(&issuer), 899);
+}
+
+#[test]
+fn test_fee_split_validation() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+
+    let cases = [
+        vec![&env, share(&a, 5_000), share(&b, 4_999)],
+        vec![&env, share(&a, 5_000), share(&a, 5_000)],
+        vec![&env, share(&a, 10_000), share(&b, 0)],
+    ];
+    for shares in cases {
+        assert_contract_error(client.try_set_fee_shares(&shares), ContractError::InvalidArgument);
+    }
+
+    // Clearing the split sends fees to `fee_dest` again.
+    client.set_fee_shares(&vec![&env, share(&a, 10_000)]);
+    client.set_fee_shares(&Vec::new(&env));
+    let token = fee_token(&env, &issuer, 100);
+    let fee_dest = Address::generate(&env);
+    client.set_fee_config(&token, &fee_dest, &10);
+    client.set_fee_enabled(&true);
+    issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    assert_eq!(token::Client::new(&env, &token).balance(&fee_dest), 10);
+}
+
+#[test]
+fn test_prepaid_credit_drawn_on_issue() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let token = fee_token(&env, &issuer, 100);
+    let fee_dest = Address::generate(&env);
+    client.set_fee_config(&token, &fee_dest, &30);
+    client.set_fee_enabled(&true);
+
+    client.deposit_credit(&issuer, &token, &60);
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&contract_id), 60);
+    assert_eq!(client.get_credit_balance(&issuer, &token), 60);
+
+    issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    issue_vc(&env, &client, &owner, &issuer, "vc-2");
+    assert_eq!(client.get_credit_balance(&issuer, &token), 0);
+    assert_eq!(token_client.balance(&fee_dest), 60);
+    assert_eq!(token_client.balance(&issuer), 40);
+
+    // An empty or partial balance is an error, never silently paid from the issuer's account.
+    let issue = |vc_id: &str| {
+        try_issue_with(
+            &env,
+            &client,
+            &owner,
+            &issuer,
+            &String::from_str(&env, vc_id),
+            &Bytes::from_slice(&env, b"<ciphertext>"),
+            "did:pkh:stellar:testnet:ISSUER",
+        )
+    };
+    assert_contract_error(issue("vc-3"), ContractError::InsufficientCredit);
+    client.deposit_credit(&issuer, &token, &10);
+    assert_contract_error(issue("vc-3"), ContractError::InsufficientCredit);
+    assert_eq!(token_client.balance(&issuer), 30);
+
+    // Closing the account refunds the rest and opts back into direct payment.
+    assert_eq!(client.close_credit(&issuer, &token), 10);
+    assert_contract_error(client.try_close_credit(&issuer, &token), ContractError::NotFound);
+    issue("vc-3").unwrap().unwrap();
+    assert_eq!(token_client.balance(&issuer), 10);
+    assert_eq!(token_client.balance(&fee_dest), 90);
+}
+
+#[test]
+fn test_withdraw_credit() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    setup_vault(&env, &client, &admin, &issuer);
+    let token = fee_token(&env, &issuer, 100);
+
+    client.deposit_credit(&issuer, &token, &50);
+    assert_contract_error(
+        client.try_withdraw_credit(&issuer, &token, &51),
+        ContractError::InsufficientCredit,
+    );
+    assert_contract_error(
+        client.try_deposit_credit(&issuer, &token, &0),
+        ContractError::InvalidArgument,
+    );
+
+    client.withdraw_credit(&issuer, &token, &20);
+    assert_eq!(client.get_credit_balance(&issuer, &token), 30);
+    client.withdraw_credit(&issuer, &token, &30);
+    assert_eq!(client.get_credit_balance(&issuer, &token), 0);
+    // The emptied account stays open until closed.
+    assert_eq!(client.close_credit(&issuer, &token), 0);
+    assert_eq!(token::Client::new(&env, &token).balance(&issuer), 100);
+}
+
+#[test]
+fn test_sponsored_issuance() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let sponsor = Address::generate(&env);
+    let token = fee_token(&env, &sponsor, 100);
+    token::StellarAssetClient::new(&env, &token).mint(&issuer, &100);
+    let fee_dest = Address::generate(&env);
+    client.set_fee_config(&token, &fee_dest, &30);
+    client.set_fee_enabled(&true);
+
+    let token_client = token::Client::new(&env, &token);
+    token_client.approve(&sponsor, &contract_id, &100, &1_000);
+    client.sponsor_issuer(&sponsor, &issuer, &token, &60, &1_000);
+    assert_eq!(client.get_sponsorship_remaining(&issuer), 0);
+    client.accept_sponsorship(&issuer, &sponsor);
+    assert_eq!(client.get_sponsorship_remaining(&issuer), 60);
+
+    issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    issue_vc(&env, &client, &owner, &issuer, "vc-2");
+    assert_eq!(token_client.balance(&sponsor), 40);
+    assert_eq!(client.get_sponsorship(&issuer).unwrap().spent, 60);
+    assert_eq!(client.get_sponsorship_remaining(&issuer), 0);
+
+    // Budget used up: the issuer pays.
+    issue_vc(&env, &client, &owner, &issuer, "vc-3");
+    assert_eq!(token_client.balance(&issuer), 70);
+    assert_eq!(token_client.balance(&fee_dest), 90);
+
+    // An unfunded sponsorship falls back to the issuer instead of blocking issuance.
+    let broke = Address::generate(&env);
+    client.sponsor_issuer(&broke, &issuer, &token, &60, &1_000);
+    client.accept_sponsorship(&issuer, &broke);
+    issue_vc(&env, &client, &owner, &issuer, "vc-4");
+    assert_eq!(token_client.balance(&issuer), 40);
+    assert_eq!(client.get_sponsorship(&issuer).unwrap().spent, 0);
+
+    // Unaccepted offers never apply; the beneficiary can end an agreement itself.
+    client.end_sponsorship(&issuer, &issuer);
+    client.sponsor_issuer(&sponsor, &issuer, &token, &40, &1_000);
+    issue_vc(&env, &client, &owner, &issuer, "vc-5");
+    assert_eq!(token_client.balance(&issuer), 10);
+    assert_eq!(token_client.balance(&sponsor), 40);
+}
+
+#[test]
+fn test_sponsorship_ownership_and_expiry() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    setup_vault(&env, &client, &admin, &issuer);
+    let sponsor = Address::generate(&env);
+    let rival = Address::generate(&env);
+    let token = fee_token(&env, &sponsor, 100);
+
+    assert_contract_error(
+        client.try_sponsor_issuer(&sponsor, &issuer, &token, &0, &1_000),
+        ContractError::InvalidArgument,
+    );
+    client.sponsor_issuer(&sponsor, &issuer, &token, &50, &1_000);
+    assert_contract_error(
+        client.try_accept_sponsorship(&issuer, &rival),
+        ContractError::NotFound,
+    );
+    client.accept_sponsorship(&issuer, &sponsor);
+    assert_contract_error(
+        client.try_sponsor_issuer(&rival, &issuer, &token, &50, &1_000),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_end_sponsorship(&rival, &issuer),
+        ContractError::Unauthorized,
+    );
+
+    // Once expired, the agreement no longer applies and can be replaced.
+    env.ledger().with_mut(|l| l.timestamp = 1_001);
+    assert_eq!(client.get_sponsorship_remaining(&issuer), 0);
+    client.sponsor_issuer(&rival, &issuer, &token, &50, &2_000);
+    assert_eq!(client.get_sponsorship(&issuer).unwrap().sponsor, rival);
+
+    client.end_sponsorship(&rival, &issuer);
+    assert_eq!(client.get_sponsorship(&issuer), None);
+    assert_contract_error(client.try_end_sponsorship(&rival, &issuer), ContractError::NotFound);
+}
+
+#[test]
+fn test_operation_fees_and_ledger() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let token = fee_token(&env, &issuer, 1_000);
+    token::StellarAssetClient::new(&env, &token).mint(&owner, &1_000);
+    let fee_dest = Address::generate(&env);
+    let partner = Address::generate(&env);
+    client.set_fee_config(&token, &fee_dest, &100);
+    client.set_fee_shares(&vec![&env, share(&fee_dest, 7_000), share(&partner, 3_000)]);
+    client.set_fee_enabled(&true);
+
+    client.set_operation_fee(&admin, &FeeOperation::Issue, &Some(50));
+    client.set_operation_fee(&admin, &FeeOperation::Push, &Some(10));
+    assert_eq!(client.get_operation_fee(&FeeOperation::Push), Some(10));
+    assert_eq!(client.get_operation_fee(&FeeOperation::CreateVault), None);
+
+    let vc_id = issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    let fee_event = env.events().all().last().unwrap();
+    assert_eq!(fee_event.1, (symbol_short!("fee"), issuer.clone()).into_val(&env));
+    let data: (FeeOperation, Address, i128, Address) = fee_event.2.into_val(&env);
+    assert_eq!(data, (FeeOperation::Issue, token.clone(), 50, issuer.clone()));
+
+    let recipient = Address::generate(&env);
+    client.create_vault(&recipient, &String::from_str(&env, "did:pkh:stellar:testnet:OTHER"));
+    client.push(&owner, &recipient, &vc_id, &issuer);
+
+    assert_eq!(client.get_fees_charged(&issuer, &token), 50);
+    assert_eq!(client.get_fees_charged(&owner, &token), 10);
+    assert_eq!(client.get_fees_charged(&recipient, &token), 0);
+    assert_eq!(client.get_fees_collected(&fee_dest, &token), 42);
+    assert_eq!(client.get_fees_collected(&partner, &token), 18);
+    assert_eq!(token::Client::new(&env, &token).balance(&owner), 990);
+
+    // Clearing the `Issue` price falls back to the global amount.
+    client.set_operation_fee(&admin, &FeeOperation::Issue, &None);
+    let education = String::from_str(&env, "EducationCredential");
+    assert_eq!(client.quote_fee(&issuer, &education).amount, 100);
+    assert_contract_error(
+        client.try_set_operation_fee(&issuer, &FeeOperation::Push, &Some(1)),
+        ContractError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_share_receipt_and_access_request_fees() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let verifier = Address::generate(&env);
+    let token = fee_token(&env, &owner, 100);
+    token::StellarAssetClient::new(&env, &token).mint(&verifier, &100);
+    client.set_fee_config(&token, &Address::generate(&env), &0);
+    client.set_fee_enabled(&true);
+    client.set_operation_fee(&admin, &FeeOperation::Share, &Some(5));
+    client.set_operation_fee(&admin, &FeeOperation::VerificationReceipt, &Some(3));
+    env.ledger().set_timestamp(1_000);
+
+    let vc_id = issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    assert_contract_error(
+        client.try_share_vc(&owner, &String::from_str(&env, "vc-9"), &verifier, &2_000),
+        ContractError::VCNotFound,
+    );
+    assert_contract_error(
+        client.try_share_vc(&owner, &vc_id, &verifier, &1_000),
+        ContractError::InvalidArgument,
+    );
+    client.share_vc(&owner, &vc_id, &verifier, &2_000);
+    assert_eq!(client.get_vc_share(&owner, &vc_id, &verifier), Some(2_000));
+    assert_eq!(client.get_fees_charged(&owner, &token), 5);
+
+    let receipt = client.record_verification(&verifier, &owner, &vc_id);
+    assert_eq!(receipt.status, String::from_str(&env, "valid"));
+    assert_eq!((receipt.owner, receipt.verified_at), (owner.clone(), 1_000));
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.1, (symbol_short!("fee"), verifier.clone()).into_val(&env));
+    assert_eq!(client.get_fees_charged(&verifier, &token), 3);
+    let receipt = client.get_verification_receipt(&verifier, &vc_id).unwrap();
+    assert_eq!(receipt.vc_id, vc_id);
+
+    // Grants lapse, can be withdrawn, and do not survive erasure.
+    env.ledger().set_timestamp(2_001);
+    assert_eq!(client.get_vc_share(&owner, &vc_id, &verifier), None);
+    client.share_vc(&owner, &vc_id, &verifier, &3_000);
+    client.unshare_vc(&owner, &vc_id, &verifier);
+    assert_contract_error(
+        client.try_unshare_vc(&owner, &vc_id, &verifier),
+        ContractError::NotFound,
+    );
+    client.share_vc(&owner, &vc_id, &verifier, &3_000);
+    client.delete_vc(&owner, &vc_id);
+    assert_eq!(client.get_vc_share(&owner, &vc_id, &verifier), None);
+    assert_eq!(client.get_fees_charged(&owner, &token), 15);
+
+    // Filing an access request costs the requester, so request slots cannot be filled for free.
+    client.set_operation_fee(&admin, &FeeOperation::AccessRequest, &Some(4));
+    let scope = Vec::new(&env);
+    client.request_issuer_access(&verifier, &owner, &scope, &5_000, &None);
+    assert_eq!(client.get_fees_charged(&verifier, &token), 7);
+    let broke = Address::generate(&env);
+    assert_contract_error(
+        client.try_request_issuer_access(&broke, &owner, &scope, &5_000, &None),
+        ContractError::FeeTransferFailed,
+    );
+    assert_eq!(client.list_issuer_requests(&owner).len(), 1);
+}
+
+#[test]
+fn test_refund_on_retraction_within_window() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let token = fee_token(&env, &issuer, 100);
+    let fee_dest = Address::generate(&env);
+    client.set_fee_config(&token, &fee_dest, &30);
+    client.set_fee_enabled(&true);
+    let policy = RefundPolicy {
+        window: 600,
+        max_refunds: 1,
+    };
+    client.set_refund_policy(&admin, &Some(policy));
+
+    let token_client = token::Client::new(&env, &token);
+    let vc_1 = issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    assert_eq!(token_client.balance(&contract_id), 30);
+    assert_eq!(client.get_fee_payment(&vc_1).unwrap().refundable_until, 600);
+
+    let date = String::from_str(&env, "2026-01-01");
+    client.retract_vc(&issuer, &vc_1, &date);
+    assert_eq!(token_client.balance(&issuer), 100);
+    assert_eq!(client.get_fees_charged(&issuer, &token), 0);
+    assert_eq!(client.get_fee_payment(&vc_1), None);
+    let status = env.as_contract(&contract_id, || storage::read_vc_status(&env, &vc_1));
+    assert_eq!(status, VCStatus::Revoked(date.clone()));
+
+    // Refund allowance used up.
+    let vc_2 = issue_vc(&env, &client, &owner, &issuer, "vc-2");
+    assert_contract_error(
+        client.try_retract_vc(&issuer, &vc_2, &date),
+        ContractError::LimitExceeded,
+    );
+
+    // Fees are paid out once the window has elapsed.
+    let vc_3 = issue_vc(&env, &client, &owner, &issuer, "vc-3");
+    env.ledger().with_mut(|l| l.timestamp = 601);
+    assert_contract_error(client.try_retract_vc(&issuer, &vc_3, &date), ContractError::Expired);
+    let missing = String::from_str(&env, "vc-9");
+    assert_eq!(client.settle_fees(&vec![&env, vc_2, vc_3.clone(), missing]), 2);
+    assert_eq!(token_client.balance(&fee_dest), 60);
+    assert_eq!(client.get_fees_collected(&fee_dest, &token), 60);
+    assert_eq!(client.settle_fees(&vec![&env, vc_3]), 0);
+}
+
+#[test]
+fn test_issue_rejects_reused_vc_id() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let token = fee_token(&env, &issuer, 100);
+    client.set_fee_config(&token, &Address::generate(&env), &30);
+    client.set_fee_enabled(&true);
+    let policy = RefundPolicy {
+        window: 600,
+        max_refunds: 5,
+    };
+    client.set_refund_policy(&admin, &Some(policy));
+
+    let vc_id = issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&contract_id), 30);
+
+    // Neither the same nor another issuer can reuse the ID, and no second fee is escrowed.
+    let rival = Address::generate(&env);
+    client.authorize_issuer(&owner, &rival);
+    client.bind_issuer_did(&rival, &pkh_did(&env, &rival));
+    let data = Bytes::from_slice(&env, b"<other>");
+    let did = "did:pkh:stellar:testnet:ISSUER";
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &vc_id, &data, did),
+        ContractError::VCAlreadyExists,
+    );
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &rival, &vc_id, &data, &pkh_did_str(&rival)),
+        ContractError::VCAlreadyExists,
+    );
+    assert_eq!(token_client.balance(&contract_id), 30);
+    assert_eq!(client.get_fee_payment(&vc_id).unwrap().amount, 30);
+    let bound = env.as_contract(&contract_id, || storage::read_vc_issuer(&env, &vc_id));
+    assert_eq!(bound, Some(issuer.clone()));
+
+    // A revoked or erased ID stays taken.
+    let date = String::from_str(&env, "2026-01-01");
+    client.retract_vc(&issuer, &vc_id, &date);
+    client.delete_vc(&owner, &vc_id);
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &rival, &vc_id, &data, &pkh_did_str(&rival)),
+        ContractError::VCAlreadyExists,
+    );
+    let status = env.as_contract(&contract_id, || storage::read_vc_status(&env, &vc_id));
+    assert_eq!(status, VCStatus::Revoked(date));
+}
+
+#[test]
+fn test_refund_guards_and_credit() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let token = fee_token(&env, &issuer, 100);
+    client.set_fee_config(&token, &Address::generate(&env), &30);
+    client.set_fee_enabled(&true);
+
+    let too_long = RefundPolicy {
+        window: MAX_REFUND_WINDOW + 1,
+        max_refunds: 5,
+    };
+    assert_contract_error(
+        client.try_set_refund_policy(&admin, &Some(too_long)),
+        ContractError::InvalidArgument,
+    );
+    let policy = RefundPolicy {
+        window: 600,
+        max_refunds: 5,
+    };
+    client.set_refund_policy(&admin, &Some(policy));
+
+    client.deposit_credit(&issuer, &token, &30);
+    let vc_id = issue_vc(&env, &client, &owner, &issuer, "vc-1");
+    assert_eq!(client.get_credit_balance(&issuer, &token), 0);
+    assert_eq!(client.get_fee_payment(&vc_id).unwrap().payer, contract_id);
+
+    let date = String::from_str(&env, "2026-01-01");
+    let stranger = Address::generate(&env);
+    assert_contract_error(
+        client.try_retract_vc(&stranger, &vc_id, &date),
+        ContractError::Unauthorized,
+    );
+
+    // Paid from credit: refunded as credit, and the issuance no longer counts towards tiers.
+    client.retract_vc(&issuer, &vc_id, &date);
+    assert_eq!(client.get_credit_balance(&issuer, &token), 30);
+    let issued = env.as_contract(&contract_id, || storage::read_issuer_issued(&env, &issuer));
+    assert_eq!(issued, 0);
+    assert_contract_error(client.try_retract_vc(&issuer, &vc_id, &date), ContractError::NotFound);
+}
+
+#[test]
+fn test_schema_registry_and_issuance() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let author = Address::generate(&env);
+    let schema_id = String::from_str(&env, "education");
+    let hash_1 = BytesN::from_array(&env, &[1; 32]);
+    let hash_2 = BytesN::from_array(&env, &[2; 32]);
+
+    client.publish_schema(&author, &schema_id, &1, &hash_1);
+    let issue = |vc_id: &str| {
+        client.try_issue(
+            &owner,
+            &String::from_str(&env, vc_id),
+            &Bytes::from_slice(&env, b"<ciphertext>"),
+            &contract_id,
+            &issuer,
+            &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+            &String::from_str(&env, "EducationCredential"),
+            &Some(schema_id.clone()),
+            &None,
+        )
+    };
+    let vc_1 = issue("vc-1").unwrap().unwrap();
+    let schema_ref = client.get_vc_schema(&vc_1).unwrap();
+    assert_eq!(schema_ref, SchemaRef { id: schema_id.clone(), version: 1 });
+    assert_eq!(client.get_schema(&schema_id, &Some(1)).hash, hash_1);
+
+    // Deprecating the latest version blocks issuance until a new version is published.
+    client.deprecate_schema(&author, &schema_id, &1);
+    assert_contract_error(issue("vc-2"), ContractError::SchemaDeprecated);
+    client.publish_schema(&author, &schema_id, &2, &hash_2);
+    let vc_2 = issue("vc-2").unwrap().unwrap();
+    assert_eq!(client.get_vc_schema(&vc_2).unwrap().version, 2);
+    assert_eq!(client.get_schema(&schema_id, &None).hash, hash_2);
+    assert_eq!(client.get_schema(&schema_id, &Some(1)).status, SchemaStatus::Deprecated);
+
+    // Re-issuing an ID cannot change or inherit its schema reference.
+    assert_contract_error(issue("vc-1"), ContractError::VCAlreadyExists);
+    assert_eq!(client.get_vc_schema(&vc_1).unwrap().version, 1);
+
+    // Erasing a VC drops its schema reference.
+    client.delete_vc(&owner, &vc_1);
+    assert_eq!(client.get_vc_schema(&vc_1), None);
+}
+
+#[test]
+fn test_schema_registry_errors() {
+    let (env, _admin, _issuer, _contract_id, client) = setup();
+    let author = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let schema_id = String::from_str(&env, "education");
+    let hash = BytesN::from_array(&env, &[1; 32]);
+
+    assert_contract_error(
+        client.try_get_schema(&schema_id, &None),
+        ContractError::SchemaNotFound,
+    );
+    assert_contract_error(
+        client.try_publish_schema(&author, &schema_id, &0, &hash),
+        ContractError::InvalidArgument,
+    );
+    client.publish_schema(&author, &schema_id, &3, &hash);
+    assert_contract_error(
+        client.try_publish_schema(&author, &schema_id, &3, &hash),
+        ContractError::InvalidArgument,
+    );
+    assert_contract_error(
+        client.try_publish_schema(&stranger, &schema_id, &4, &hash),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_deprecate_schema(&stranger, &schema_id, &3),
+        ContractError::Unauthorized,
+    );
+    assert_contract_error(
+        client.try_deprecate_schema(&author, &schema_id, &2),
+        ContractError::SchemaNotFound,
+    );
+}
+
+#[test]
+fn test_payload_commitments() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let sha256 = |data: &[u8]| env.crypto().sha256(&Bytes::from_slice(&env, data)).to_bytes();
+    let plaintext_hash = sha256(b"{\"degree\":\"BSc\"}");
+
+    let vc_id = String::from_str(&env, "vc-1");
+    client.issue(
+        &owner,
+        &vc_id,
+        &Bytes::from_slice(&env, b"<ciphertext>"),
+        &contract_id,
+        &issuer,
+        &String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+        &String::from_str(&env, "EducationCredential"),
+        &None,
+        &Some(plaintext_hash.clone()),
+    );
+
+    assert_eq!(client.get_vc_payload_hash(&vc_id), Some(sha256(b"<ciphertext>")));
+    assert_eq!(client.get_vc_commitment(&vc_id), Some(plaintext_hash.clone()));
+    assert!(client.verify_commitment(&vc_id, &sha256(b"<ciphertext>")));
+    assert!(client.verify_commitment(&vc_id, &plaintext_hash));
+    assert!(!client.verify_commitment(&vc_id, &sha256(b"<tampered>")));
+
+    // Re-issuing the ID without a commitment cannot leave the old one behind.
+    let did = "did:pkh:stellar:testnet:ISSUER";
+    let other = Bytes::from_slice(&env, b"<other>");
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &vc_id, &other, did),
+        ContractError::VCAlreadyExists,
+    );
+    assert_eq!(client.get_vc_payload_hash(&vc_id), Some(sha256(b"<ciphertext>")));
+    assert!(!client.verify_commitment(&vc_id, &sha256(b"<other>")));
+
+    // Without an issuer commitment only the ciphertext hash is recorded.
+    let vc_2 = issue_vc(&env, &client, &owner, &issuer, "vc-2");
+    assert_eq!(client.get_vc_commitment(&vc_2), None);
+    assert!(client.verify_commitment(&vc_2, &sha256(b"<ciphertext>")));
+    assert_contract_error(
+        client.try_verify_commitment(&String::from_str(&env, "vc-9"), &plaintext_hash),
+        ContractError::VCNotFound,
+    );
+
+    // Erasing a VC drops its hashes along with the payload.
+    client.delete_vc(&owner, &vc_id);
+    assert_eq!(client.get_vc_payload_hash(&vc_id), None);
+    assert_eq!(client.get_vc_commitment(&vc_id), None);
+    assert!(!client.verify_commitment(&vc_id, &plaintext_hash));
+}
+
+#[test]
+fn test_large_payload_chunked_and_reassembled() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let total = 2 * PAYLOAD_CHUNK_SIZE + 100;
+    client.set_validation_limits(&ValidationLimits {
+        max_payload_len: total,
+        ..DEFAULT_LIMITS
+    });
+
+    let raw: std::vec::Vec<u8> = (0..total).map(|i| i as u8).collect();
+    let payload = Bytes::from_slice(&env, &raw);
+    let vc_id = String::from_str(&env, "vc-large");
+    let did = "did:pkh:stellar:testnet:ISSUER";
+    env.budget().reset_default();
+    try_issue_with(&env, &client, &owner, &issuer, &vc_id, &payload, did)
+        .unwrap()
+        .unwrap();
+    env.budget().reset_default();
+    assert_eq!(client.get_vc(&owner, &vc_id).unwrap().data, payload);
+    let hash = env.crypto().sha256(&payload).to_bytes();
+    assert!(client.verify_commitment(&vc_id, &hash));
+
+    // Chunks follow the VC between vaults and are dropped with it.
+    let to_owner = Address::generate(&env);
+    client.create_vault(&to_owner, &String::from_str(&env, "did:pkh:stellar:testnet:OTHER"));
+    env.budget().reset_default();
+    client.push(&owner, &to_owner, &vc_id, &issuer);
+    env.budget().reset_default();
+    assert_eq!(client.get_vc(&to_owner, &vc_id).unwrap().data, payload);
+    client.delete_vc(&to_owner, &vc_id);
+    env.as_contract(&contract_id, || {
+        assert!(storage::read_vault_vc(&env, &to_owner, &vc_id).is_none());
+    });
+}
+
+#[test]
+fn test_payload_upload_in_parts() {
+    let (env, admin, issuer, _contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let vc_id = String::from_str(&env, "vc-1");
+    let part_1 = Bytes::from_slice(&env, b"<cipher");
+    let part_2 = Bytes::from_slice(&env, b"text>");
+
+    assert_eq!(client.upload_vc_part(&issuer, &owner, &vc_id, &part_1), 7);
+    assert_eq!(client.upload_vc_part(&issuer, &owner, &vc_id, &part_2), 12);
+    let upload = client.get_vc_upload(&owner, &vc_id).unwrap();
+    assert_eq!((upload.parts, upload.len), (2, 12));
+
+    let stranger = Address::generate(&env);
+    assert_contract_error(
+        client.try_cancel_vc_upload(&stranger, &owner, &vc_id),
+        ContractError::Unauthorized,
+    );
+    let oversized = Bytes::from_array(&env, &[0; PAYLOAD_CHUNK_SIZE as usize + 1]);
+    assert_contract_error(
+        client.try_upload_vc_part(&issuer, &owner, &vc_id, &oversized),
+        ContractError::PayloadTooLarge,
+    );
+
+    // An empty payload issues the upload.
+    let did = "did:pkh:stellar:testnet:ISSUER";
+    try_issue_with(&env, &client, &owner, &issuer, &vc_id, &Bytes::new(&env), did)
+        .unwrap()
+        .unwrap();
+    let vc = client.get_vc(&owner, &vc_id).unwrap();
+    assert_eq!(vc.data, Bytes::from_slice(&env, b"<ciphertext>"));
+    assert_eq!(client.get_vc_upload(&owner, &vc_id), None);
+
+    let vc_2 = String::from_str(&env, "vc-2");
+    client.upload_vc_part(&issuer, &owner, &vc_2, &part_1);
+    client.cancel_vc_upload(&issuer, &owner, &vc_2);
+    assert_contract_error(
+        client.try_cancel_vc_upload(&issuer, &owner, &vc_2),
+        ContractError::NotFound,
+    );
+}
+
+#[test]
+fn test_payload_upload_expires() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let vc_id = String::from_str(&env, "vc-1");
+    let part = Bytes::from_slice(&env, b"<ciphertext>");
+    let did = "did:pkh:stellar:testnet:ISSUER";
+    env.ledger().set_timestamp(1_000);
+
+    client.upload_vc_part(&issuer, &owner, &vc_id, &part);
+    assert_eq!(client.get_vc_upload(&owner, &vc_id).unwrap().expires_at, 1_000 + UPLOAD_TTL);
+
+    // An expired upload is hidden and cannot be issued.
+    env.ledger().set_timestamp(1_001 + UPLOAD_TTL);
+    assert_eq!(client.get_vc_upload(&owner, &vc_id), None);
+    assert_contract_error(
+        try_issue_with(&env, &client, &owner, &issuer, &vc_id, &Bytes::new(&env), did),
+        ContractError::Expired,
+    );
+
+    // Another issuer may start over in its place.
+    let other = Address::generate(&env);
+    client.authorize_issuer(&owner, &other);
+    assert_eq!(client.upload_vc_part(&other, &owner, &vc_id, &part), 12);
+    assert_eq!(client.get_vc_upload(&owner, &vc_id).unwrap().issuer, other);
+
+    // Pending uploads per vault are capped; expired ones make room.
+    for i in 1..MAX_PENDING_UPLOADS {
+        client.upload_vc_part(&issuer, &owner, &vc_id_at(&env, i), &part);
+    }
+    let extra = String::from_str(&env, "vc-extra");
+    assert_contract_error(
+        client.try_upload_vc_part(&issuer, &owner, &extra, &part),
+        ContractError::LimitExceeded,
+    );
+    env.ledger().set_timestamp(2_002 + 2 * UPLOAD_TTL);
+    client.upload_vc_part(&issuer, &owner, &extra, &part);
+    env.as_contract(&contract_id, || {
+        assert_eq!(storage::read_vc_uploads(&env, &owner), vec![&env, extra.clone()]);
+        assert_eq!(storage::read_vc_upload(&env, &owner, &vc_id), None);
+    });
+}
+
+#[test]
+fn test_migrate_string_payloads() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+
+    // Odd-length ASCII, non-ASCII (multi-byte UTF-8) and 4-byte-aligned payloads.
+    let payloads = ["<ciphertext>!", "ñandú ✓", "12345678"];
+    let total: u32 = 30;
+    env.as_contract(&contract_id, || {
+        storage::remove_vault_bytes_layout(&env, &owner);
+        for i in 0..total {
+            let vc = StringVerifiableCredential {
+                id: vc_id_at(&env, i),
+                data: String::from_str(&env, payloads[i as usize % payloads.len()]),
+                issuance_contract: contract_id.clone(),
+                issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+                credential_type: String::from_str(&env, "EducationCredential"),
+            };
+            storage::write_string_vault_vc(&env, &owner, &vc);
+            storage::append_vault_vc_id(&env, &owner, &vc.id);
+        }
+    });
+
+    // String payloads read as their raw UTF-8 bytes before migration.
+    for (i, payload) in payloads.iter().enumerate() {
+        let data = client.get_vc(&owner, &vc_id_at(&env, i as u32)).unwrap().data;
+        assert_eq!(data, Bytes::from_slice(&env, payload.as_bytes()));
+    }
+    assert_eq!(client.migration_progress(&Some(owner.clone())).remaining, total);
+
+    env.budget().reset_default();
+    assert!(!client.migrate(&Some(owner.clone())).done);
+    env.budget().reset_default();
+    assert!(client.migrate(&Some(owner.clone())).done);
+
+    env.as_contract(&contract_id, || {
+        for i in 0..total {
+            assert!(storage::read_string_vault_vc(&env, &owner, &vc_id_at(&env, i)).is_none());
+        }
+    });
+    for i in total - 3..total {
+        let payload = payloads[i as usize % payloads.len()];
+        let data = client.get_vc(&owner, &vc_id_at(&env, i)).unwrap().data;
+        assert_eq!(data, Bytes::from_slice(&env, payload.as_bytes()));
+    }
+    assert_contract_error(
+        client.try_migrate(&Some(owner.clone())),
+        ContractError::VCSAlreadyMigrated,
+    );
+}
+
+#[test]
+fn test_read_and_migrate_baseline_vault_vcs() {
+    let (env, admin, issuer, contract_id, client) = setup();
+    let owner = setup_vault(&env, &client, &admin, &issuer);
+    let other = Address::generate(&env);
+    client.create_vault(&other, &String::from_str(&env, "did:pkh:stellar:testnet:OTHER"));
+
+    // Entries exactly as the baseline contract wrote them (4 fields, no credential type).
+    let total: u32 = 3;
+    env.as_contract(&contract_id, || {
+        storage::remove_vault_bytes_layout(&env, &owner);
+        for i in 0..total {
+            let vc = BaselineVerifiableCredential {
+                id: vc_id_at(&env, i),
+                data: String::from_str(&env, "<ciphertext>"),
+                issuance_contract: contract_id.clone(),
+                issuer_did: String::from_str(&env, "did:pkh:stellar:testnet:ISSUER"),
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKey::VaultVC(owner.clone(), vc.id.clone()), &vc);
+            storage::append_vault_vc_id(&env, &owner, &vc.id);
+        }
+    });
+
+    let ciphertext = Bytes::from_slice(&env, b"<ciphertext>");
+    let vc = client.get_vc(&owner, &vc_id_at(&env, 0)).unwrap();
+    assert_eq!(vc.data, ciphertext);
+    assert_eq!(vc.credential_type, String::from_str(&env, ""));
+    client.push(&owner, &other, &vc_id_at(&env, 0), &issuer);
+    assert_eq!(client.get_vc(&other, &vc_id_at(&env, 0)).unwrap().data, ciphertext);
+
+    assert_eq!(client.migration_progress(&Some(owner.clone())).remaining, total - 1);
+    assert!(client.migrate(&Some(owner.clone())).done);
+    env.as_contract(&contract_id, || {
+        for i in 1..total {
+            assert!(storage::read_string_vault_vc(&env, &owner, &vc_id_at(&env, i)).is_none());
+        }
+    });
+    assert_eq!(client.get_vc(&owner, &vc_id_at(&env, total - 1)).unwrap().data, ciphertext);
 }
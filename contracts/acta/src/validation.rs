@@ -0,0 +1,152 @@
+use crate::error::ContractError;
+use crate::storage;
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, Env, String};
+
+/// Default maximum length (bytes) of a VC ID.
+pub const DEFAULT_MAX_ID_LEN: u32 = 128;
+
+/// Default maximum length (bytes) of a DID.
+pub const DEFAULT_MAX_DID_LEN: u32 = 256;
+
+/// Default maximum size (bytes) of a VC payload.
+pub const DEFAULT_MAX_PAYLOAD_LEN: u32 = 16 * 1024;
+
+/// Largest fee amount: basis-point splits and discounts multiply it by up to 10000.
+pub const MAX_FEE_AMOUNT: i128 = i128::MAX / 10_000;
+
+/// Hard upper bound for `max_did_len`: DIDs are copied into a fixed buffer to be parsed.
+pub const DID_LEN_CAP: u32 = 512;
+
+/// Input limits enforced on IDs, DIDs and payloads (admin-configurable).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationLimits {
+    /// Maximum length (bytes) of a VC ID.
+    pub max_id_len: u32,
+
+    /// Maximum length (bytes) of a DID. Must not exceed `DID_LEN_CAP`.
+    pub max_did_len: u32,
+
+    /// Maximum size (bytes) of a VC payload.
+    pub max_payload_len: u32,
+}
+
+/// Limits applied until the admin configures others.
+pub const DEFAULT_LIMITS: ValidationLimits = ValidationLimits {
+    max_id_len: DEFAULT_MAX_ID_LEN,
+    max_did_len: DEFAULT_MAX_DID_LEN,
+    max_payload_len: DEFAULT_MAX_PAYLOAD_LEN,
+};
+
+/// Length of a Stellar strkey (`G...` account or `C...` contract).
+const STRKEY_LEN: usize = 56;
+
+/// Length of the shortest possible DID (`did:a:b`).
+const DID_MIN_LEN: u32 = 7;
+
+/// Rejects limits that would block every input or overflow the DID parse buffer.
+pub fn validate_limits(e: &Env, limits: &ValidationLimits) {
+    if limits.max_id_len == 0
+        || limits.max_payload_len == 0
+        || limits.max_did_len < DID_MIN_LEN
+        || limits.max_did_len > DID_LEN_CAP
+    {
+        panic_with_error!(e, ContractError::InvalidValidationLimits)
+    }
+}
+
+/// IDs (VC IDs, DID method/service IDs) must be non-empty and at most `max_id_len` bytes.
+pub fn validate_id(e: &Env, vc_id: &String) {
+    let limits = storage::read_validation_limits(e);
+    if vc_id.is_empty() || vc_id.len() > limits.max_id_len {
+        panic_with_error!(e, ContractError::InvalidId)
+    }
+}
+
+/// VC payloads must be non-empty and at most `max_payload_len` bytes.
+pub fn validate_payload(e: &Env, vc_data: &Bytes) {
+    validate_payload_len(e, vc_data.len());
+}
+
+/// Payload-sized values must be non-empty and at most `max_payload_len` bytes.
+pub fn validate_payload_len(e: &Env, len: u32) {
+    let limits = storage::read_validation_limits(e);
+    if len == 0 || len > limits.max_payload_len {
+        panic_with_error!(e, ContractError::PayloadTooLarge)
+    }
+}
+
+/// DIDs must follow `did:<method>:<method-specific-id>`.
+///
+/// - `method` is one or more lowercase letters or digits.
+/// - `method-specific-id` is non-empty.
+pub fn validate_did(e: &Env, did: &String) {
+    let limits = storage::read_validation_limits(e);
+    let len = did.len();
+    if len > limits.max_did_len || len > DID_LEN_CAP {
+        panic_with_error!(e, ContractError::InvalidDid)
+    }
+
+    let mut buf = [0u8; DID_LEN_CAP as usize];
+    let bytes = &mut buf[..len as usize];
+    did.copy_into_slice(bytes);
+
+    if !is_did(bytes) {
+        panic_with_error!(e, ContractError::InvalidDid)
+    }
+}
+
+/// Whether `did` is `did:pkh:stellar:<network>:<address>` for exactly this address.
+///
+/// Control of such a DID is proven on-chain by the address's own signature.
+pub fn is_stellar_pkh_did(did: &String, address: &Address) -> bool {
+    let len = did.len();
+    if len > DID_LEN_CAP {
+        return false;
+    }
+    let mut buf = [0u8; DID_LEN_CAP as usize];
+    let bytes = &mut buf[..len as usize];
+    did.copy_into_slice(bytes);
+
+    let strkey = address.to_string();
+    let mut key_buf = [0u8; STRKEY_LEN];
+    if strkey.len() as usize != STRKEY_LEN {
+        return false;
+    }
+    strkey.copy_into_slice(&mut key_buf);
+
+    let rest = match bytes.strip_prefix(b"did:pkh:stellar:") {
+        Some(rest) => rest,
+        None => return false,
+    };
+    match rest.iter().rposition(|b| *b == b':') {
+        Some(n) => n > 0 && rest[n + 1..] == key_buf,
+        None => false,
+    }
+}
+
+/// Fee amounts must be non-negative and at most `MAX_FEE_AMOUNT`.
+pub fn validate_fee_amount(e: &Env, amount: i128) {
+    if !(0..=MAX_FEE_AMOUNT).contains(&amount) {
+        panic_with_error!(e, ContractError::InvalidFeeAmount)
+    }
+}
+
+fn is_did(bytes: &[u8]) -> bool {
+    let rest = match bytes.strip_prefix(b"did:") {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let method_len = match rest.iter().position(|b| *b == b':') {
+        Some(n) => n,
+        None => return false,
+    };
+    let (method, id) = (&rest[..method_len], &rest[method_len + 1..]);
+
+    !method.is_empty()
+        && method
+            .iter()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        && !id.is_empty()
+}
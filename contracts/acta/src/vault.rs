@@ -0,0 +1,268 @@
+use crate::error::ContractError;
+use crate::{storage, vault_admins, verifiable_credential};
+use crate::vc_status::VCStatus;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String, Vec};
+
+/// Maximum number of VCs deleted by a single `close_vault` call.
+pub const CLOSE_BATCH_SIZE: u32 = 25;
+
+/// Maximum number of VCs re-keyed by a single `rotate_vault_owner` call.
+pub const ROTATION_BATCH_SIZE: u32 = 25;
+
+/// Maximum number of aliases followed when resolving a rotated owner.
+pub const MAX_ALIAS_HOPS: u32 = 8;
+
+/// Public snapshot of a vault's configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultInfo {
+    /// Vault owner.
+    pub owner: Address,
+
+    /// Current vault admin.
+    pub admin: Address,
+
+    /// Owner DID URI set at creation.
+    pub did: String,
+
+    /// Whether the vault is revoked (writes blocked).
+    pub revoked: bool,
+
+    /// Whether a `close_vault` is in progress.
+    pub closing: bool,
+
+    /// Number of authorized issuers.
+    pub issuer_count: u32,
+
+    /// Number of VCs stored in the vault.
+    pub vc_count: u32,
+}
+
+/// Progress of a paginated vault closure.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CloseProgress {
+    /// VCs deleted by this call.
+    pub removed: u32,
+
+    /// VCs still stored in the vault.
+    pub remaining: u32,
+
+    /// `true` once every vault entry was deleted.
+    pub done: bool,
+}
+
+/// Progress of a paginated owner rotation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RotationProgress {
+    /// VCs re-keyed by this call.
+    pub moved: u32,
+
+    /// VCs still stored under the old owner.
+    pub remaining: u32,
+
+    /// `true` once every entry was moved and the alias was written.
+    pub done: bool,
+}
+
+pub fn info(e: &Env, owner: &Address) -> VaultInfo {
+    let did = match storage::read_vault_did(e, owner) {
+        Some(did) => did,
+        None => panic_with_error!(e, ContractError::VaultNotInitialized),
+    };
+
+    VaultInfo {
+        owner: owner.clone(),
+        admin: storage::read_vault_admin(e, owner),
+        did,
+        revoked: storage::read_vault_revoked(e, owner),
+        closing: storage::read_vault_closing(e, owner),
+        issuer_count: storage::read_vault_issuers(e, owner).len(),
+        vc_count: storage::read_vault_vc_ids(e, owner).len(),
+    }
+}
+
+pub fn issuers(e: &Env, owner: &Address) -> Vec<Address> {
+    storage::read_vault_issuers(e, owner).keys()
+}
+
+/// Lifts a revocation; closing or rotating vaults cannot be restored.
+pub fn restore(e: &Env, owner: &Address) {
+    if !storage::read_vault_revoked(e, owner)
+        || storage::read_vault_closing(e, owner)
+        || is_rotating(e, owner)
+    {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    storage::write_vault_revoked(e, owner, &false);
+}
+
+/// Deletes the next batch of the vault's VCs; the last call removes the vault itself.
+///
+/// The vault is revoked for the duration of the closure. Deleted VCs still `Valid` in the
+/// status registry become `Invalid`; revoked ones keep their revocation.
+pub fn close_batch(e: &Env, owner: &Address) -> CloseProgress {
+    if is_rotating(e, owner) {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    storage::write_vault_revoked(e, owner, &true);
+    storage::write_vault_closing(e, owner, &true);
+
+    let mut ids = storage::read_vault_vc_ids(e, owner);
+    let removed = ids.len().min(CLOSE_BATCH_SIZE);
+    for _ in 0..removed {
+        let vc_id = ids.pop_back_unchecked();
+        verifiable_credential::erase(e, owner, &vc_id);
+        if storage::read_vc_status(e, &vc_id) == VCStatus::Valid {
+            storage::write_vc_status(e, &vc_id, &VCStatus::Invalid);
+        }
+    }
+
+    if !ids.is_empty() {
+        storage::write_vault_vc_ids(e, owner, &ids);
+        return CloseProgress {
+            removed,
+            remaining: ids.len(),
+            done: false,
+        };
+    }
+
+    for issuer in storage::read_vault_issuers(e, owner).keys().iter() {
+        storage::remove_issuer_terms(e, owner, &issuer);
+    }
+    storage::remove_vault_issuers(e, owner);
+    storage::remove_issuer_requests(e, owner);
+    storage::remove_guardians(e, owner);
+    storage::remove_recovery(e, owner);
+    vault_admins::clear(e, owner);
+    verifiable_credential::remove_uploads(e, owner);
+    storage::remove_vault_vc_ids(e, owner);
+    storage::remove_vault_metadata(e, owner);
+
+    CloseProgress {
+        removed,
+        remaining: 0,
+        done: true,
+    }
+}
+
+/// Whether a rotation moves the vault away from, or onto, `owner`.
+pub fn is_rotating(e: &Env, owner: &Address) -> bool {
+    storage::read_vault_rotation(e, owner).is_some()
+        || storage::read_vault_rotation_source(e, owner).is_some()
+}
+
+/// Follows owner aliases left by completed rotations to the address holding the vault.
+pub fn resolve(e: &Env, owner: &Address) -> Address {
+    let mut current = owner.clone();
+    for _ in 0..MAX_ALIAS_HOPS {
+        if storage::has_vault_admin(e, &current) {
+            break;
+        }
+        match storage::read_vault_alias(e, &current) {
+            Some(next) => current = next,
+            None => break,
+        }
+    }
+    current
+}
+
+/// Starts a rotation: creates the new vault, moves the issuer set and freezes both vaults.
+///
+/// A vault admin equal to the old owner follows the vault to the new owner.
+pub fn start_rotation(e: &Env, old: &Address, new: &Address) {
+    if old == new {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+    if storage::has_vault_admin(e, new) {
+        panic_with_error!(e, ContractError::AlreadyInitialized)
+    }
+
+    let mut admin = storage::read_vault_admin(e, old);
+    if admin == *old {
+        admin = new.clone();
+    }
+    let did = match storage::read_vault_did(e, old) {
+        Some(did) => did,
+        None => panic_with_error!(e, ContractError::VaultNotInitialized),
+    };
+    storage::write_vault_admin(e, new, &admin);
+    storage::write_vault_did(e, new, &did);
+    storage::write_vault_revoked(e, new, &true);
+    storage::write_vault_bytes_layout(e, new);
+    storage::remove_vault_alias(e, new);
+
+    let issuers = storage::read_vault_issuers(e, old);
+    for issuer in issuers.keys().iter() {
+        if let Some(terms) = storage::read_issuer_terms(e, old, &issuer) {
+            storage::write_issuer_terms(e, new, &issuer, &terms);
+            storage::remove_issuer_terms(e, old, &issuer);
+        }
+    }
+    storage::write_vault_issuers(e, new, &issuers);
+    storage::remove_vault_issuers(e, old);
+    storage::write_issuer_requests(e, new, &storage::read_issuer_requests(e, old));
+    storage::remove_issuer_requests(e, old);
+    if let Some(guardians) = storage::read_guardians(e, old) {
+        storage::write_guardians(e, new, &guardians);
+        storage::remove_guardians(e, old);
+    }
+    storage::remove_recovery(e, old);
+    if let Some(set) = storage::read_vault_admin_set(e, old) {
+        storage::write_vault_admin_set(e, new, &set);
+    }
+    vault_admins::clear(e, old);
+
+    storage::write_vault_revoked(e, old, &true);
+    storage::write_vault_rotation(e, old, new);
+    storage::write_vault_rotation_source(e, new, old);
+}
+
+/// Re-keys the next batch of VCs; the last call removes the old vault and leaves an alias.
+pub fn rotate_batch(e: &Env, old: &Address, new: &Address) -> RotationProgress {
+    let mut old_ids = storage::read_vault_vc_ids(e, old);
+    let mut new_ids = storage::read_vault_vc_ids(e, new);
+
+    // Oldest first, so the new index keeps the original order.
+    let moved = old_ids.len().min(ROTATION_BATCH_SIZE);
+    for _ in 0..moved {
+        let vc_id = old_ids.pop_back_unchecked();
+        if let Some(vc) = storage::read_vault_vc(e, old, &vc_id) {
+            storage::write_vault_vc(e, new, &vc_id, &vc);
+            storage::remove_vault_vc(e, old, &vc_id);
+        }
+        let shares = storage::read_vc_shares(e, old, &vc_id);
+        storage::write_vc_shares(e, new, &vc_id, &shares);
+        storage::remove_vc_shares(e, old, &vc_id);
+        if storage::read_vc_owner(e, &vc_id) == Some(old.clone()) {
+            storage::write_vc_owner(e, &vc_id, new);
+        }
+        new_ids.push_front(vc_id);
+    }
+    storage::write_vault_vc_ids(e, new, &new_ids);
+
+    if !old_ids.is_empty() {
+        storage::write_vault_vc_ids(e, old, &old_ids);
+        return RotationProgress {
+            moved,
+            remaining: old_ids.len(),
+            done: false,
+        };
+    }
+
+    // Uploads are keyed by owner; issuers restart them against the new address.
+    verifiable_credential::remove_uploads(e, old);
+    storage::remove_vault_vc_ids(e, old);
+    storage::remove_vault_metadata(e, old);
+    storage::remove_vault_rotation(e, old);
+    storage::remove_vault_rotation_source(e, new);
+    storage::write_vault_alias(e, old, new);
+    storage::write_vault_revoked(e, new, &false);
+
+    RotationProgress {
+        moved,
+        remaining: 0,
+        done: true,
+    }
+}
@@ -0,0 +1,261 @@
+use crate::error::ContractError;
+use crate::issuer::IssuerTerms;
+use crate::{issuer, issuer_request, migration, recovery, storage, vault};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, String, Vec};
+
+/// Maximum number of administrators in a vault admin set.
+pub const MAX_VAULT_ADMINS: u32 = 10;
+
+/// Maximum number of pending proposals per vault.
+pub const MAX_PENDING_PROPOSALS: u32 = 20;
+
+/// M-of-N administrators of a vault.
+///
+/// While configured, issuer management, access request handling, vault revocation and
+/// restoration, recovery cancellation, migrations and admin changes only go through proposals.
+/// Closing, rotating, erasing VCs and guardian changes are not proposable: hand the vault back
+/// to a single admin (`SetVaultAdmin`) first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminSet {
+    /// Administrator addresses.
+    pub admins: Vec<Address>,
+
+    /// Approvals required to execute a proposal.
+    pub threshold: u32,
+}
+
+/// Vault admin action subject to threshold approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    AuthorizeIssuer(Address),
+
+    /// Issuer authorized with a window, cap and/or types (`issued` is ignored).
+    AuthorizeIssuerWithLimits(Address, IssuerTerms),
+
+    /// Issuers to add and to remove, as in `update_issuers`.
+    UpdateIssuers(Vec<Address>, Vec<Address>),
+    SetIssuerCredentialTypes(Address, Vec<String>),
+    ApproveIssuerRequest(Address),
+    RevokeIssuer(Address),
+
+    /// Issuers whose pending access requests are denied.
+    DenyIssuerRequests(Vec<Address>),
+    PurgeIssuerRequests,
+    RevokeVault,
+    RestoreVault,
+    CancelRecovery,
+
+    /// Migrates the next batch of the vault's legacy VCs.
+    MigrateVault,
+
+    /// Hands the vault back to a single admin; clears the admin set and pending proposals.
+    SetVaultAdmin(Address),
+}
+
+/// Pending admin action.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    /// Proposal identifier (unique per vault).
+    pub id: u32,
+
+    /// Action executed once the threshold is met.
+    pub action: AdminAction,
+
+    /// Administrators that approved (the proposer included).
+    pub approvals: Vec<Address>,
+
+    /// Ledger timestamp when the proposal was created.
+    pub created_at: u64,
+
+    /// Ledger timestamp after which the proposal lapses.
+    pub expires_at: u64,
+}
+
+pub fn set_admins(e: &Env, owner: &Address, admins: &Vec<Address>, threshold: u32) {
+    if admins.len() > MAX_VAULT_ADMINS {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+    for (i, admin) in admins.iter().enumerate() {
+        if admins.first_index_of(&admin) != Some(i as u32) {
+            panic_with_error!(e, ContractError::InvalidArgument)
+        }
+    }
+    if threshold == 0 || threshold > admins.len() {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+
+    let set = AdminSet {
+        admins: admins.clone(),
+        threshold,
+    };
+    storage::write_vault_admin_set(e, owner, &set);
+}
+
+/// Whether the vault is under M-of-N control.
+pub fn has_admin_set(e: &Env, owner: &Address) -> bool {
+    storage::read_vault_admin_set(e, owner).is_some()
+}
+
+/// Creates a proposal approved by `admin`; executes it right away at threshold 1.
+pub fn propose(
+    e: &Env,
+    owner: &Address,
+    admin: &Address,
+    action: &AdminAction,
+    expires_at: u64,
+) -> u32 {
+    let set = read_set(e, owner);
+    validate_member(e, &set, admin);
+    if expires_at <= e.ledger().timestamp() {
+        panic_with_error!(e, ContractError::InvalidArgument)
+    }
+
+    let mut proposals = read_pending(e, owner);
+    if proposals.len() >= MAX_PENDING_PROPOSALS {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+
+    let id = storage::read_vault_proposal_seq(e, owner);
+    storage::write_vault_proposal_seq(e, owner, &(id + 1));
+
+    let proposal = Proposal {
+        id,
+        action: action.clone(),
+        approvals: Vec::from_array(e, [admin.clone()]),
+        created_at: e.ledger().timestamp(),
+        expires_at,
+    };
+    if set.threshold <= 1 {
+        storage::write_vault_proposals(e, owner, &proposals);
+        execute(e, owner, &proposal.action);
+        return id;
+    }
+
+    proposals.set(id, proposal);
+    storage::write_vault_proposals(e, owner, &proposals);
+    id
+}
+
+/// Adds `admin`'s approval; executes the proposal once the threshold is met.
+///
+/// Returns `true` if the proposal was executed.
+pub fn approve(e: &Env, owner: &Address, admin: &Address, id: u32) -> bool {
+    let set = read_set(e, owner);
+    validate_member(e, &set, admin);
+
+    let mut proposals = storage::read_vault_proposals(e, owner);
+    let mut proposal = match proposals.get(id) {
+        Some(proposal) => proposal,
+        None => panic_with_error!(e, ContractError::NotFound),
+    };
+    if proposal.expires_at < e.ledger().timestamp() {
+        panic_with_error!(e, ContractError::Expired)
+    }
+
+    if !proposal.approvals.contains(admin) {
+        proposal.approvals.push_back(admin.clone());
+    }
+    if proposal.approvals.len() < set.threshold {
+        proposals.set(id, proposal);
+        storage::write_vault_proposals(e, owner, &proposals);
+        return false;
+    }
+
+    proposals.remove(id);
+    storage::write_vault_proposals(e, owner, &proposals);
+    execute(e, owner, &proposal.action);
+    true
+}
+
+/// Lists pending, unexpired proposals.
+pub fn list(e: &Env, owner: &Address) -> Vec<Proposal> {
+    read_pending(e, owner).values()
+}
+
+/// Drops the admin set and its proposals.
+pub fn clear(e: &Env, owner: &Address) {
+    storage::remove_vault_admin_set(e, owner);
+    storage::remove_vault_proposals(e, owner);
+}
+
+fn execute(e: &Env, owner: &Address, action: &AdminAction) {
+    // Clean-up and restoration also apply to revoked vaults.
+    let on_revoked = matches!(
+        action,
+        AdminAction::DenyIssuerRequests(_)
+            | AdminAction::PurgeIssuerRequests
+            | AdminAction::RestoreVault
+            | AdminAction::CancelRecovery
+            | AdminAction::MigrateVault
+    );
+    if !on_revoked && storage::read_vault_revoked(e, owner) {
+        panic_with_error!(e, ContractError::VaultRevoked)
+    }
+
+    match action {
+        AdminAction::AuthorizeIssuer(issuer_addr) => {
+            issuer::authorize_issuer(e, owner, issuer_addr)
+        }
+        AdminAction::AuthorizeIssuerWithLimits(issuer_addr, terms) => {
+            let terms = IssuerTerms {
+                issued: 0,
+                ..terms.clone()
+            };
+            issuer::authorize_issuer_with_terms(e, owner, issuer_addr, &terms)
+        }
+        AdminAction::UpdateIssuers(add, remove) => {
+            issuer::update_issuers(e, owner, add, remove);
+        }
+        AdminAction::SetIssuerCredentialTypes(issuer_addr, credential_types) => {
+            issuer::set_credential_types(e, owner, issuer_addr, credential_types)
+        }
+        AdminAction::ApproveIssuerRequest(issuer_addr) => {
+            issuer_request::approve(e, owner, issuer_addr)
+        }
+        AdminAction::RevokeIssuer(issuer_addr) => issuer::revoke_issuer(e, owner, issuer_addr),
+        AdminAction::DenyIssuerRequests(issuers) => {
+            issuer_request::deny_many(e, owner, issuers);
+        }
+        AdminAction::PurgeIssuerRequests => {
+            issuer_request::purge(e, owner);
+        }
+        AdminAction::RevokeVault => storage::write_vault_revoked(e, owner, &true),
+        AdminAction::RestoreVault => vault::restore(e, owner),
+        AdminAction::CancelRecovery => recovery::cancel(e, owner),
+        AdminAction::MigrateVault => {
+            migration::migrate_vault_batch(e, owner);
+        }
+        AdminAction::SetVaultAdmin(new_admin) => {
+            storage::write_vault_admin(e, owner, new_admin);
+            clear(e, owner);
+        }
+    }
+}
+
+fn read_set(e: &Env, owner: &Address) -> AdminSet {
+    match storage::read_vault_admin_set(e, owner) {
+        Some(set) => set,
+        None => panic_with_error!(e, ContractError::NotFound),
+    }
+}
+
+fn validate_member(e: &Env, set: &AdminSet, admin: &Address) {
+    if !set.admins.contains(admin) {
+        panic_with_error!(e, ContractError::Unauthorized)
+    }
+}
+
+/// Reads the vault's proposals, dropping the expired ones.
+fn read_pending(e: &Env, owner: &Address) -> Map<u32, Proposal> {
+    let now = e.ledger().timestamp();
+    let mut proposals = storage::read_vault_proposals(e, owner);
+    for (id, proposal) in proposals.clone().iter() {
+        if proposal.expires_at < now {
+            proposals.remove(id);
+        }
+    }
+    proposals
+}
@@ -3,7 +3,7 @@ use crate::storage;
 use soroban_sdk::{contracttype, panic_with_error, Env, String};
 
 /// Status registry entry for a VC ID.
-#[derive(PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub enum VCStatus {
     /// VC exists and is currently valid.
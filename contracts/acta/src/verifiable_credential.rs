@@ -1,5 +1,19 @@
+use crate::error::ContractError;
 use crate::storage;
-use soroban_sdk::{contracttype, Address, Env, String};
+use crate::validation::DEFAULT_MAX_PAYLOAD_LEN;
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, Address, Bytes, BytesN, Env, String, Vec,
+};
+
+/// Payloads larger than this (bytes) are split across several storage entries; it is also the
+/// largest part accepted by `upload_part`.
+pub const PAYLOAD_CHUNK_SIZE: u32 = 8 * 1024;
+
+/// Seconds a pending upload stays usable after its first part.
+pub const UPLOAD_TTL: u64 = 24 * 60 * 60;
+
+/// Maximum number of pending uploads per vault.
+pub const MAX_PENDING_UPLOADS: u32 = 20;
 
 /// Verifiable Credential stored in a vault.
 ///
@@ -11,30 +25,286 @@ pub struct VerifiableCredential {
     pub id: String,
 
     /// VC payload (ciphertext or reference).
-    pub data: String,
+    pub data: Bytes,
 
     /// Issuance contract that can verify/revoke the VC status.
     pub issuance_contract: Address,
 
     /// Issuer DID (metadata for wallets/UX).
     pub issuer_did: String,
+
+    /// Credential type (e.g. `EmploymentCredential`). Empty for VCs migrated from legacy layouts.
+    pub credential_type: String,
+}
+
+/// VC layout of `VaultVC` entries, before payloads were stored as bytes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StringVerifiableCredential {
+    pub id: String,
+    pub data: String,
+    pub issuance_contract: Address,
+    pub issuer_did: String,
+    pub credential_type: String,
+}
+
+/// VC layout of `VaultVC` entries written by the baseline contract (no credential type).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BaselineVerifiableCredential {
+    pub id: String,
+    pub data: String,
+    pub issuance_contract: Address,
+    pub issuer_did: String,
+}
+
+/// Number of fields of `BaselineVerifiableCredential` (a stored struct is a map of its fields).
+pub const BASELINE_VC_FIELDS: u32 = 4;
+
+/// VC layout of legacy vaults (`LegacyVaultVCs`), before credential types existed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyVerifiableCredential {
+    pub id: String,
+    pub data: String,
+    pub issuance_contract: Address,
+    pub issuer_did: String,
+}
+
+/// Payload being uploaded in parts ahead of `issue`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayloadUpload {
+    /// Issuer uploading (and later issuing) the payload.
+    pub issuer: Address,
+
+    /// Parts uploaded so far.
+    pub parts: u32,
+
+    /// Bytes uploaded so far.
+    pub len: u32,
+
+    /// Ledger timestamp after which the upload can no longer be issued and may be replaced.
+    pub expires_at: u64,
+}
+
+pub fn store_vc(e: &Env, owner: &Address, vc: &VerifiableCredential) {
+    storage::write_vault_vc(e, owner, &vc.id, vc);
+    storage::append_vault_vc_id(e, owner, &vc.id);
+}
+
+/// Rejects a VC ID already in the status registry, bound to an issuer or holding a fee.
+pub fn validate_unused_id(e: &Env, vc_id: &String) {
+    if storage::has_vc_status(e, vc_id)
+        || storage::read_vc_issuer(e, vc_id).is_some()
+        || storage::read_fee_payment(e, vc_id).is_some()
+    {
+        panic_with_error!(e, ContractError::VCAlreadyExists)
+    }
 }
 
-pub fn store_vc(
+/// Removes a VC from the vault; the status registry is left untouched.
+///
+/// Emits `("vc_del", owner)` with the VC ID so indexers can prune copies.
+pub fn delete_vc(e: &Env, owner: &Address, vc_id: &String) {
+    if storage::read_vault_vc(e, owner, vc_id).is_none() {
+        panic_with_error!(e, ContractError::VCNotFound)
+    }
+    erase(e, owner, vc_id);
+    storage::remove_vault_vc_id(e, owner, vc_id);
+
+    e.events()
+        .publish((symbol_short!("vc_del"), owner.clone()), vc_id.clone());
+}
+
+/// Removes a VC's payload with its shares, schema reference, payload hash and commitment.
+///
+/// The VC index is left to the caller.
+pub fn erase(e: &Env, owner: &Address, vc_id: &String) {
+    storage::remove_vault_vc(e, owner, vc_id);
+    storage::remove_vc_shares(e, owner, vc_id);
+    storage::remove_vc_schema(e, vc_id);
+    storage::remove_vc_payload_hash(e, vc_id);
+    storage::remove_vc_commitment(e, vc_id);
+}
+
+/// Records the SHA-256 of `vc`'s payload and the issuer's commitment of its plaintext.
+///
+/// Only called for new IDs (see `validate_unused_id`), so no earlier hash or commitment remains.
+pub fn commit(e: &Env, vc: &VerifiableCredential, plaintext: &Option<BytesN<32>>) {
+    storage::write_vc_payload_hash(e, &vc.id, &payload_hash(e, &vc.data));
+    if let Some(plaintext) = plaintext {
+        storage::write_vc_commitment(e, &vc.id, plaintext);
+    }
+}
+
+/// `true` if `hash` is the SHA-256 of the payload issued as `vc_id` or of its plaintext.
+pub fn verify_commitment(e: &Env, vc_id: &String, hash: &BytesN<32>) -> bool {
+    storage::read_vc_payload_hash(e, vc_id).as_ref() == Some(hash)
+        || storage::read_vc_commitment(e, vc_id).as_ref() == Some(hash)
+}
+
+pub fn payload_hash(e: &Env, data: &Bytes) -> BytesN<32> {
+    e.crypto().sha256(data).to_bytes()
+}
+
+/// Appends a part to the payload `issuer` is uploading for `owner`'s `vc_id`.
+///
+/// An expired upload, by any issuer, is discarded and a new one started. Returns the bytes
+/// uploaded so far.
+pub fn upload_part(
     e: &Env,
     owner: &Address,
-    id: String,
-    data: String,
-    issuance_contract: Address,
-    issuer_did: String,
-) {
-    let new_vc: VerifiableCredential = VerifiableCredential {
-        id: id.clone(),
-        data,
-        issuance_contract,
-        issuer_did,
+    issuer: &Address,
+    vc_id: &String,
+    part: &Bytes,
+) -> u32 {
+    if part.is_empty() || part.len() > PAYLOAD_CHUNK_SIZE {
+        panic_with_error!(e, ContractError::PayloadTooLarge)
+    }
+    let mut upload = match storage::read_vc_upload(e, owner, vc_id) {
+        Some(upload) if is_expired(e, &upload) => {
+            remove_upload(e, owner, vc_id, &upload);
+            start_upload(e, owner, issuer, vc_id)
+        }
+        Some(upload) if upload.issuer != *issuer => {
+            panic_with_error!(e, ContractError::Unauthorized)
+        }
+        Some(upload) => upload,
+        None => start_upload(e, owner, issuer, vc_id),
     };
+    let len = upload.len + part.len();
+    if len > storage::read_validation_limits(e).max_payload_len {
+        panic_with_error!(e, ContractError::PayloadTooLarge)
+    }
+
+    storage::write_vc_upload_part(e, owner, vc_id, upload.parts, part);
+    upload.parts += 1;
+    upload.len = len;
+    storage::write_vc_upload(e, owner, vc_id, &upload);
+    len
+}
+
+pub fn cancel_upload(e: &Env, owner: &Address, issuer: &Address, vc_id: &String) {
+    match storage::read_vc_upload(e, owner, vc_id) {
+        Some(upload) if upload.issuer == *issuer => remove_upload(e, owner, vc_id, &upload),
+        Some(_) => panic_with_error!(e, ContractError::Unauthorized),
+        None => panic_with_error!(e, ContractError::NotFound),
+    }
+}
+
+/// Returns the pending upload for `vc_id`, unless it has expired.
+pub fn pending_upload(e: &Env, owner: &Address, vc_id: &String) -> Option<PayloadUpload> {
+    storage::read_vc_upload(e, owner, vc_id).filter(|upload| !is_expired(e, upload))
+}
+
+/// Reassembles and clears `issuer`'s upload for `vc_id`, if any.
+pub fn take_upload(e: &Env, owner: &Address, issuer: &Address, vc_id: &String) -> Option<Bytes> {
+    let upload = storage::read_vc_upload(e, owner, vc_id)?;
+    if upload.issuer != *issuer {
+        panic_with_error!(e, ContractError::Unauthorized)
+    }
+    if is_expired(e, &upload) {
+        panic_with_error!(e, ContractError::Expired)
+    }
+
+    let mut data = Bytes::new(e);
+    for index in 0..upload.parts {
+        if let Some(part) = storage::read_vc_upload_part(e, owner, vc_id, index) {
+            data.append(&part);
+        }
+    }
+    remove_upload(e, owner, vc_id, &upload);
+    Some(data)
+}
+
+/// Converts a VC stored before payloads were bytes.
+pub fn from_string_layout(e: &Env, vc: StringVerifiableCredential) -> VerifiableCredential {
+    VerifiableCredential {
+        id: vc.id,
+        data: string_to_bytes(e, &vc.data),
+        issuance_contract: vc.issuance_contract,
+        issuer_did: vc.issuer_did,
+        credential_type: vc.credential_type,
+    }
+}
+
+pub fn from_baseline_layout(
+    e: &Env,
+    vc: BaselineVerifiableCredential,
+) -> StringVerifiableCredential {
+    StringVerifiableCredential {
+        id: vc.id,
+        data: vc.data,
+        issuance_contract: vc.issuance_contract,
+        issuer_did: vc.issuer_did,
+        credential_type: String::from_str(e, ""),
+    }
+}
+
+/// Raw bytes of a string payload, copied through a buffer of `DEFAULT_MAX_PAYLOAD_LEN` bytes.
+pub fn string_to_bytes(e: &Env, s: &String) -> Bytes {
+    let mut buf = [0u8; DEFAULT_MAX_PAYLOAD_LEN as usize];
+    let len = s.len() as usize;
+    if len > buf.len() {
+        panic_with_error!(e, ContractError::PayloadTooLarge)
+    }
+    s.copy_into_slice(&mut buf[..len]);
+    Bytes::from_slice(e, &buf[..len])
+}
+
+/// Discards every pending upload into `owner`'s vault.
+pub fn remove_uploads(e: &Env, owner: &Address) {
+    for vc_id in storage::read_vc_uploads(e, owner).iter() {
+        if let Some(upload) = storage::read_vc_upload(e, owner, &vc_id) {
+            remove_upload_parts(e, owner, &vc_id, &upload);
+        }
+    }
+    storage::remove_vc_uploads(e, owner);
+}
+
+/// Registers a new upload in the vault's index, pruning expired ones to make room.
+fn start_upload(e: &Env, owner: &Address, issuer: &Address, vc_id: &String) -> PayloadUpload {
+    let mut vc_ids = Vec::new(e);
+    for pending in storage::read_vc_uploads(e, owner).iter() {
+        match storage::read_vc_upload(e, owner, &pending) {
+            Some(upload) if is_expired(e, &upload) => {
+                remove_upload_parts(e, owner, &pending, &upload)
+            }
+            Some(_) => vc_ids.push_back(pending),
+            None => {}
+        }
+    }
+    if vc_ids.len() >= MAX_PENDING_UPLOADS {
+        panic_with_error!(e, ContractError::LimitExceeded)
+    }
+    vc_ids.push_back(vc_id.clone());
+    storage::write_vc_uploads(e, owner, &vc_ids);
+
+    PayloadUpload {
+        issuer: issuer.clone(),
+        parts: 0,
+        len: 0,
+        expires_at: e.ledger().timestamp() + UPLOAD_TTL,
+    }
+}
+
+fn is_expired(e: &Env, upload: &PayloadUpload) -> bool {
+    e.ledger().timestamp() > upload.expires_at
+}
+
+fn remove_upload(e: &Env, owner: &Address, vc_id: &String, upload: &PayloadUpload) {
+    remove_upload_parts(e, owner, vc_id, upload);
+    let mut vc_ids = storage::read_vc_uploads(e, owner);
+    if let Some(index) = vc_ids.first_index_of(vc_id) {
+        vc_ids.remove(index);
+        storage::write_vc_uploads(e, owner, &vc_ids);
+    }
+}
 
-    storage::write_vault_vc(e, owner, &id, &new_vc);
-    storage::append_vault_vc_id(e, owner, &id);
+fn remove_upload_parts(e: &Env, owner: &Address, vc_id: &String, upload: &PayloadUpload) {
+    for index in 0..upload.parts {
+        storage::remove_vc_upload_part(e, owner, vc_id, index);
+    }
+    storage::remove_vc_upload(e, owner, vc_id);
 }